@@ -0,0 +1,458 @@
+//! CSV，TSV，JSON Linesの表の一列に含まれる法令IDや法令番号のまとめての処理
+//!
+//! 入力の各行をそのままの順で出力し，処理の結果を列として加える．
+//! 処理に失敗した行も`error`列に理由を書いて出力し，処理は中断しない．
+//! 加える列と同じ名前の列が入力にある場合は，入力を上書きしないよう`BatchError::ColumnConflict`で中断する
+
+use crate::*;
+use serde_json::{Map, Value};
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+/// 表の形式
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum BatchFormat {
+    /// 見出し行のあるカンマ区切り
+    Csv,
+    /// 見出し行のあるタブ区切り
+    Tsv,
+    /// 一行に一つのJSONのオブジェクト
+    JsonLines,
+}
+
+/// 各行の値に行う処理
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum BatchOperation {
+    /// 法令IDを解析する
+    Parse,
+    /// 法令IDを解析し，`LawId::validate`で検査する
+    Validate,
+    /// 法令番号を法令IDに変換し，`law_id`列に加える
+    ToId,
+    /// 法令IDを法令番号に変換し，`law_num`列に加える
+    ToNum,
+}
+
+/// 加える列の名前（変換の結果の列を除く）
+const ADDED_COLUMNS: [&str; 5] = ["era", "ad_year", "law_type", "ministries", "error"];
+
+/// まとめての処理の設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchOptions {
+    pub format: BatchFormat,
+    /// 処理する値を含む列の名前
+    pub column: String,
+    pub operation: BatchOperation,
+    /// `BatchOperation::ToNum`で生成する法令番号の表記
+    pub law_num_options: LawNumOptions,
+}
+
+/// 一つの値に処理を行った結果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchRecord {
+    /// 解析した法令ID．法令番号から一意に定まらない場合は候補をすべて含む
+    pub law_ids: Vec<LawId>,
+    /// `BatchOperation::ToNum`で生成した法令番号
+    pub law_num: Option<String>,
+    /// 失敗した理由
+    pub error: Option<String>,
+}
+
+impl BatchRecord {
+    fn failed(error: impl ToString) -> Self {
+        Self {
+            error: Some(error.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// 加える列の名前と値
+    ///
+    /// 元号，西暦年，種別，府省は最初の法令IDから求める．
+    /// 法令番号の候補はどれも元号，年，種別，府省が同じになる
+    fn columns(&self, operation: BatchOperation) -> Vec<(&'static str, Value)> {
+        let law_id = self.law_ids.first();
+        let ministries: Vec<Value> = match law_id.map(|id| &id.law_type) {
+            Some(LawType::MinistryOrder { ministry, .. }) => ministry
+                .agency_names()
+                .into_iter()
+                .map(Value::from)
+                .collect(),
+            Some(LawType::Regulation { institution, .. }) => {
+                vec![Value::from(institution.agency_name())]
+            }
+            _ => Vec::new(),
+        };
+        let mut columns = Vec::new();
+        match operation {
+            BatchOperation::ToId => {
+                let ids: Vec<String> = self.law_ids.iter().map(|id| id.to_id_str()).collect();
+                let ids = (!ids.is_empty()).then(|| ids.join(" "));
+                columns.push(("law_id", Value::from(ids)));
+            }
+            BatchOperation::ToNum => columns.push(("law_num", Value::from(self.law_num.clone()))),
+            BatchOperation::Parse | BatchOperation::Validate => (),
+        }
+        let values = [
+            Value::from(law_id.map(|id| id.wareki.get_era().to_text())),
+            Value::from(law_id.map(|id| id.wareki.to_ad())),
+            Value::from(law_id.map(|id| id.law_type.name())),
+            Value::from(ministries),
+            Value::from(self.error.clone()),
+        ];
+        columns.extend(ADDED_COLUMNS.into_iter().zip(values));
+        columns
+    }
+}
+
+/// 処理に失敗した行
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BatchFailure {
+    /// 入力での1から始まる行の番号．CSVとTSVでは見出し行を1行目として数える
+    pub line: usize,
+    pub error: String,
+}
+
+/// まとめての処理の結果
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BatchSummary {
+    /// 処理した行の数
+    pub rows: usize,
+    pub failures: Vec<BatchFailure>,
+}
+
+impl BatchSummary {
+    fn push(&mut self, line: usize, record: &BatchRecord) {
+        self.rows += 1;
+        if let Some(error) = &record.error {
+            self.failures.push(BatchFailure {
+                line,
+                error: error.clone(),
+            });
+        }
+    }
+}
+
+/// 表を読み書きできず，処理を続けられないときのエラー
+#[derive(Debug)]
+pub enum BatchError {
+    Io(io::Error),
+    Csv(csv::Error),
+    /// JSON Linesの行がJSONのオブジェクトとして読めない
+    InvalidJson {
+        line: usize,
+        message: String,
+    },
+    /// 指定された列がCSVやTSVの見出し行に無い
+    MissingColumn(String),
+    /// 入力に加える列と同じ名前の列がある
+    ColumnConflict {
+        line: usize,
+        column: String,
+    },
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{e}"),
+            Self::Csv(e) => write!(f, "{e}"),
+            Self::InvalidJson { line, message } => {
+                write!(f, "invalid JSON at line {line}: {message}")
+            }
+            Self::MissingColumn(column) => write!(f, "column {column:?} is not in the header"),
+            Self::ColumnConflict { line, column } => write!(
+                f,
+                "column {column:?} at line {line} conflicts with an added column"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Csv(e) => Some(e),
+            Self::InvalidJson { .. } | Self::MissingColumn(_) | Self::ColumnConflict { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for BatchError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<csv::Error> for BatchError {
+    fn from(e: csv::Error) -> Self {
+        Self::Csv(e)
+    }
+}
+
+impl BatchOptions {
+    pub fn new(format: BatchFormat, column: &str, operation: BatchOperation) -> Self {
+        Self {
+            format,
+            column: String::from(column),
+            operation,
+            law_num_options: LawNumOptions::default(),
+        }
+    }
+
+    /// 一つの値に処理を行う．前後の空白は無視する
+    pub fn apply(&self, value: &str) -> BatchRecord {
+        let value = value.trim();
+        let law_id = match self.operation {
+            BatchOperation::ToId => {
+                return match LawId::candidates_from_law_num(value) {
+                    Ok(law_ids) => BatchRecord {
+                        law_ids,
+                        ..BatchRecord::default()
+                    },
+                    Err(e) => BatchRecord::failed(e),
+                };
+            }
+            _ => match LawId::from_id_str(value) {
+                Ok(law_id) => law_id,
+                Err(e) => return BatchRecord::failed(e),
+            },
+        };
+        let mut record = BatchRecord {
+            law_ids: vec![law_id],
+            ..BatchRecord::default()
+        };
+        match self.operation {
+            BatchOperation::Validate => {
+                let diagnostics: Vec<String> =
+                    law_id.validate().iter().map(|d| d.to_string()).collect();
+                if !diagnostics.is_empty() {
+                    record.error = Some(diagnostics.join("; "));
+                }
+            }
+            BatchOperation::ToNum => {
+                record.law_num = law_id.to_law_num_with(&self.law_num_options);
+                if record.law_num.is_none() {
+                    record.error = Some(String::from("no law number for this law type"));
+                }
+            }
+            BatchOperation::Parse | BatchOperation::ToId => (),
+        }
+        record
+    }
+
+    /// 表を読み，列を加えて書き出す
+    ///
+    /// 行の処理に失敗しても中断せず，失敗した行を`BatchSummary::failures`に含める
+    pub fn run<R: Read, W: Write>(&self, reader: R, writer: W) -> Result<BatchSummary, BatchError> {
+        match self.format {
+            BatchFormat::Csv => self.run_csv(reader, writer, b','),
+            BatchFormat::Tsv => self.run_csv(reader, writer, b'\t'),
+            BatchFormat::JsonLines => self.run_json_lines(reader, writer),
+        }
+    }
+
+    fn run_csv<R: Read, W: Write>(
+        &self,
+        reader: R,
+        writer: W,
+        delimiter: u8,
+    ) -> Result<BatchSummary, BatchError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(delimiter)
+            .flexible(true)
+            .from_reader(reader);
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(delimiter)
+            .flexible(true)
+            .from_writer(writer);
+        let headers = reader.headers()?.clone();
+        let index = headers
+            .iter()
+            .position(|h| h == self.column)
+            .ok_or_else(|| BatchError::MissingColumn(self.column.clone()))?;
+        let added = BatchRecord::default().columns(self.operation);
+        if let Some((name, _)) = added
+            .iter()
+            .find(|(name, _)| headers.iter().any(|h| h == *name))
+        {
+            return Err(BatchError::ColumnConflict {
+                line: 1,
+                column: String::from(*name),
+            });
+        }
+        writer.write_record(headers.iter().chain(added.iter().map(|(name, _)| *name)))?;
+        let mut summary = BatchSummary::default();
+        for row in reader.records() {
+            let row = row?;
+            let line = row.position().map_or(0, |p| p.line() as usize);
+            let record = match row.get(index) {
+                Some(value) => self.apply(value),
+                None => BatchRecord::failed(format!("column {:?} is missing", self.column)),
+            };
+            summary.push(line, &record);
+            // 見出し行より短い行は，加える列が見出しとずれないよう空のセルで埋める
+            let padding = headers.len().saturating_sub(row.len());
+            let cells = record
+                .columns(self.operation)
+                .into_iter()
+                .map(|(_, value)| csv_cell(value));
+            writer.write_record(
+                row.iter()
+                    .map(String::from)
+                    .chain(std::iter::repeat_n(String::new(), padding))
+                    .chain(cells),
+            )?;
+        }
+        writer.flush()?;
+        Ok(summary)
+    }
+
+    fn run_json_lines<R: Read, W: Write>(
+        &self,
+        reader: R,
+        mut writer: W,
+    ) -> Result<BatchSummary, BatchError> {
+        let mut summary = BatchSummary::default();
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut object: Map<String, Value> =
+                serde_json::from_str(&line).map_err(|e| BatchError::InvalidJson {
+                    line: i + 1,
+                    message: e.to_string(),
+                })?;
+            let record = match object.get(&self.column) {
+                Some(Value::String(value)) => self.apply(value),
+                Some(_) => BatchRecord::failed(format!("column {:?} is not a string", self.column)),
+                None => BatchRecord::failed(format!("column {:?} is missing", self.column)),
+            };
+            let columns = record.columns(self.operation);
+            if let Some((name, _)) = columns.iter().find(|(name, _)| object.contains_key(*name)) {
+                return Err(BatchError::ColumnConflict {
+                    line: i + 1,
+                    column: String::from(*name),
+                });
+            }
+            summary.push(i + 1, &record);
+            for (name, value) in columns {
+                object.insert(String::from(name), value);
+            }
+            serde_json::to_writer(&mut writer, &object).map_err(io::Error::from)?;
+            writeln!(writer)?;
+        }
+        writer.flush()?;
+        Ok(summary)
+    }
+}
+
+/// CSVのセルの文字列にする．府省の列は「・」でつなぐ
+fn csv_cell(value: Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s,
+        Value::Array(values) => values
+            .iter()
+            .map(|v| v.as_str().unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("・"),
+        value => value.to_string(),
+    }
+}
+
+#[test]
+fn check_batch_csv() {
+    let input = "name,id\n電波法施行規則,325M50001000004\n不正,505XX0000000001\n\"憲法, 日本国\",321CONSTITUTION\n";
+    let options = BatchOptions::new(BatchFormat::Csv, "id", BatchOperation::Parse);
+    let mut output = Vec::new();
+    let summary = options.run(input.as_bytes(), &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "name,id,era,ad_year,law_type,ministries,error
+電波法施行規則,325M50001000004,昭和,1950,府省令,郵政省,
+不正,505XX0000000001,,,,,unknown law type at byte 3
+\"憲法, 日本国\",321CONSTITUTION,昭和,1946,憲法,,
+"
+    );
+    assert_eq!(summary.rows, 3);
+    assert_eq!(
+        summary.failures,
+        vec![BatchFailure {
+            line: 3,
+            error: String::from("unknown law type at byte 3")
+        }]
+    );
+    let options = BatchOptions::new(BatchFormat::Tsv, "番号", BatchOperation::Parse);
+    assert!(matches!(
+        options.run("id\n321CONSTITUTION\n".as_bytes(), Vec::new()),
+        Err(BatchError::MissingColumn(_))
+    ));
+}
+
+#[test]
+fn check_batch_json_lines() {
+    let input = r#"{"title":"日本国憲法","num":"昭和二十一年憲法"}
+{"title":"電波法施行規則","num":"昭和二十五年郵政省令第四号"}
+
+{"title":"民法","num":"明治二十九年法律第八十九号"}
+{"title":"不明"}
+"#;
+    let options = BatchOptions::new(BatchFormat::JsonLines, "num", BatchOperation::ToId);
+    let mut output = Vec::new();
+    let summary = options.run(input.as_bytes(), &mut output).unwrap();
+    let lines: Vec<Value> = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[1]["law_id"], "325M50001000004");
+    assert_eq!(lines[1]["ministries"], serde_json::json!(["郵政省"]));
+    assert_eq!(lines[1]["error"], Value::Null);
+    assert_eq!(lines[2]["title"], "民法");
+    assert_eq!(lines[2]["ad_year"], 1896);
+    assert_eq!(lines[3]["law_id"], Value::Null);
+    assert_eq!(summary.rows, 4);
+    assert_eq!(
+        summary.failures.iter().map(|f| f.line).collect::<Vec<_>>(),
+        vec![1, 5]
+    );
+}
+
+#[test]
+fn check_batch_short_rows() {
+    let input = "id,name,note
+325M50001000004
+321CONSTITUTION,日本国憲法
+";
+    let options = BatchOptions::new(BatchFormat::Csv, "id", BatchOperation::Parse);
+    let mut output = Vec::new();
+    options.run(input.as_bytes(), &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "id,name,note,era,ad_year,law_type,ministries,error
+325M50001000004,,,昭和,1950,府省令,郵政省,
+321CONSTITUTION,日本国憲法,,昭和,1946,憲法,,
+"
+    );
+}
+
+#[test]
+fn check_batch_column_conflict() {
+    let options = BatchOptions::new(BatchFormat::Csv, "id", BatchOperation::Parse);
+    assert!(matches!(
+        options.run("id,era
+321CONSTITUTION,昭和
+".as_bytes(), Vec::new()),
+        Err(BatchError::ColumnConflict { line: 1, column }) if column == "era"
+    ));
+    let options = BatchOptions::new(BatchFormat::JsonLines, "num", BatchOperation::ToId);
+    let input =
+        "{\"num\":\"昭和二十一年憲法\"}\n\n{\"num\":\"昭和二十一年憲法\",\"law_id\":\"x\"}\n";
+    assert!(matches!(
+        options.run(input.as_bytes(), Vec::new()),
+        Err(BatchError::ColumnConflict { line: 3, column }) if column == "law_id"
+    ));
+}
@@ -0,0 +1,464 @@
+//! 法令IDの説明，検査，法令番号との相互変換を行うコマンド
+//!
+//! 結果は一件ごとに，テキストでは複数行，JSONでは一行（JSON Lines）で出力する
+
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use japanese_law_id::*;
+use serde_json::{Value, json};
+use std::fs::File;
+use std::io::{self, BufRead, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "japanese-law-id",
+    version,
+    about = "Parse, explain and validate Japanese law IDs"
+)]
+struct Cli {
+    /// Output format [default: text]; not accepted by batch, which writes its input format
+    #[arg(long, value_enum, global = true)]
+    format: Option<Format>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+impl Cli {
+    /// 出力の形式．`batch`は入力と同じ形式で出力するため，指定されるとエラーにする
+    fn output_format(&self) -> Result<Format, clap::Error> {
+        match (&self.command, self.format) {
+            (Command::Batch { .. }, Some(_)) => Err(Cli::command().error(
+                ErrorKind::ArgumentConflict,
+                "--format cannot be used with batch; use --input-format",
+            )),
+            (_, format) => Ok(format.unwrap_or(Format::Text)),
+        }
+    }
+}
+
+/// 出力の形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// `Numeral`のコマンドライン引数での表記
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+enum NumeralArg {
+    /// 六十
+    #[default]
+    Kansuji,
+    /// 60
+    Arabic,
+    /// ６０
+    FullWidth,
+}
+
+impl From<NumeralArg> for Numeral {
+    fn from(n: NumeralArg) -> Self {
+        match n {
+            NumeralArg::Kansuji => Numeral::Kansuji,
+            NumeralArg::Arabic => Numeral::Arabic,
+            NumeralArg::FullWidth => Numeral::FullWidth,
+        }
+    }
+}
+
+/// 法令番号の表記の指定
+#[derive(Debug, Args)]
+struct LawNumArgs {
+    /// Numerals used for the year and the number
+    #[arg(long, value_enum, default_value_t)]
+    numeral: NumeralArg,
+    /// Write the first year of an era as a number instead of 元年
+    #[arg(long)]
+    no_gannen: bool,
+}
+
+impl LawNumArgs {
+    fn options(&self) -> LawNumOptions {
+        LawNumOptions {
+            numeral: self.numeral.into(),
+            gannen: !self.no_gannen,
+        }
+    }
+}
+
+/// `BatchFormat`のコマンドライン引数での表記
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum BatchFormatArg {
+    Csv,
+    Tsv,
+    Jsonl,
+}
+
+/// `BatchOperation`のコマンドライン引数での表記
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum BatchOperationArg {
+    /// Parse law IDs
+    Parse,
+    /// Parse and validate law IDs
+    Validate,
+    /// Convert law numbers to law IDs
+    ToId,
+    /// Convert law IDs to law numbers
+    ToNum,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the era, year, law type and ministries of law IDs in Japanese and English
+    Explain {
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
+    /// Check law IDs read from standard input, one per line, and fail if any is invalid
+    Validate,
+    /// Convert a law number such as 令和五年法律第六十号 to law IDs
+    ToId { law_num: String },
+    /// Convert law IDs to law numbers
+    ToNum {
+        #[arg(required = true)]
+        ids: Vec<String>,
+        #[command(flatten)]
+        law_num: LawNumArgs,
+    },
+    /// Process a column of a CSV, TSV or JSON Lines table and add decoded columns
+    ///
+    /// The table is written to standard output in the input format, with the columns
+    /// era, ad_year, law_type, ministries and error added. Failed rows are reported
+    /// on standard error and do not stop the processing.
+    Batch {
+        /// Name of the column holding law IDs or law numbers
+        #[arg(long)]
+        column: String,
+        #[arg(long, value_enum, default_value_t = BatchOperationArg::Parse)]
+        operation: BatchOperationArg,
+        /// Format of the input and output tables
+        #[arg(long, value_enum, default_value_t = BatchFormatArg::Csv)]
+        input_format: BatchFormatArg,
+        /// Input file; standard input if omitted
+        file: Option<PathBuf>,
+        #[command(flatten)]
+        law_num: LawNumArgs,
+    },
+}
+
+/// 一件分の出力
+struct Output {
+    text: String,
+    json: Value,
+    ok: bool,
+}
+
+impl Output {
+    fn error(input: &str, e: &LawIdError) -> Self {
+        Self {
+            text: format!("{input}: error: {e}"),
+            json: json!({
+                "input": input,
+                "error": e.to_string(),
+                "position": e.position(),
+            }),
+            ok: false,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let format = cli.output_format().unwrap_or_else(|e| e.exit());
+    let outputs: Vec<Output> = match &cli.command {
+        Command::Explain { ids } => ids.iter().map(|s| explain(s)).collect(),
+        Command::Validate => {
+            let stdin = io::stdin();
+            let mut outputs = Vec::new();
+            for line in stdin.lock().lines() {
+                match line {
+                    Ok(line) if line.trim().is_empty() => (),
+                    Ok(line) => outputs.push(validate(line.trim())),
+                    Err(e) => {
+                        eprintln!("error: {e}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            outputs
+        }
+        Command::ToId { law_num } => vec![to_id(law_num)],
+        Command::ToNum { ids, law_num } => {
+            let options = law_num.options();
+            ids.iter().map(|s| to_num(s, &options)).collect()
+        }
+        Command::Batch {
+            column,
+            operation,
+            input_format,
+            file,
+            law_num,
+        } => {
+            let format = match input_format {
+                BatchFormatArg::Csv => BatchFormat::Csv,
+                BatchFormatArg::Tsv => BatchFormat::Tsv,
+                BatchFormatArg::Jsonl => BatchFormat::JsonLines,
+            };
+            let operation = match operation {
+                BatchOperationArg::Parse => BatchOperation::Parse,
+                BatchOperationArg::Validate => BatchOperation::Validate,
+                BatchOperationArg::ToId => BatchOperation::ToId,
+                BatchOperationArg::ToNum => BatchOperation::ToNum,
+            };
+            let options = BatchOptions {
+                law_num_options: law_num.options(),
+                ..BatchOptions::new(format, column, operation)
+            };
+            return batch(&options, file.as_ref());
+        }
+    };
+    let mut ok = true;
+    for output in outputs {
+        ok &= output.ok;
+        match format {
+            Format::Text if output.ok => println!("{}", output.text),
+            Format::Text => eprintln!("{}", output.text),
+            Format::Json => println!("{}", output.json),
+        }
+    }
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// 表をまとめて処理し，失敗した行を標準エラー出力に書く
+fn batch(options: &BatchOptions, file: Option<&PathBuf>) -> ExitCode {
+    let reader: Box<dyn Read> = match file {
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(file),
+            Err(e) => {
+                eprintln!("error: {}: {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Box::new(io::stdin().lock()),
+    };
+    match options.run(reader, io::stdout().lock()) {
+        Ok(summary) => {
+            for failure in &summary.failures {
+                eprintln!("line {}: error: {}", failure.line, failure.error);
+            }
+            if summary.failures.is_empty() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// 府・省の名称，英語名，ビット
+fn ministry_entries<T: MinistryContents>(
+    set: MinistrySet<T>,
+) -> Vec<(&'static str, &'static str, usize)> {
+    set.iter()
+        .map(|m| (m.agency_name(), m.english_name(), m.to_int()))
+        .collect()
+}
+
+/// 法令の種別に含まれる府・省や機関
+fn issuers(law_type: &LawType) -> Vec<(&'static str, &'static str, usize)> {
+    match law_type {
+        LawType::MinistryOrder { ministry, .. } => match *ministry {
+            Ministry::M1(set) => ministry_entries(set),
+            Ministry::M2(set) => ministry_entries(set),
+            Ministry::M3(set) => ministry_entries(set),
+            Ministry::M4(set) => ministry_entries(set),
+            Ministry::M5(set) => ministry_entries(set),
+            Ministry::M6(set) => ministry_entries(set),
+        },
+        LawType::Regulation { institution, .. } => vec![(
+            institution.agency_name(),
+            institution.english_name(),
+            institution.to_int(),
+        )],
+        _ => Vec::new(),
+    }
+}
+
+fn explain(s: &str) -> Output {
+    let law_id = match LawId::from_id_str(s) {
+        Ok(law_id) => law_id,
+        Err(e) => return Output::error(s, &e),
+    };
+    let era = law_id.wareki.get_era();
+    let year = law_id.wareki.get_year();
+    let law_type = &law_id.law_type;
+    let issuers = issuers(law_type);
+    let law_num = law_id.to_law_num();
+
+    let mut text = vec![
+        format!("法令ID / Law ID: {law_id}"),
+        format!("元号 / Era: {} ({})", era.to_text(), era.as_xml_str()),
+        format!("年 / Year: {year} ({})", law_id.wareki.to_ad()),
+        format!(
+            "種別 / Law type: {} ({})",
+            law_type.name(),
+            law_type.english_name()
+        ),
+    ];
+    if !issuers.is_empty() {
+        text.push(String::from("府省・機関 / Issuers:"));
+        for (name, english_name, _) in &issuers {
+            text.push(format!("  {name} ({english_name})"));
+        }
+    }
+    if let Some(law_num) = &law_num {
+        text.push(format!("法令番号 / Law number: {law_num}"));
+    }
+    Output {
+        text: text.join("\n"),
+        json: json!({
+            "id": law_id.to_id_str(),
+            "era": { "ja": era.to_text(), "en": era.as_xml_str() },
+            "year": year,
+            "ad_year": law_id.wareki.to_ad(),
+            "law_type": { "ja": law_type.name(), "en": law_type.english_name() },
+            "issuers": issuers
+                .iter()
+                .map(|(name, english_name, bit)| json!({ "ja": name, "en": english_name, "bit": bit }))
+                .collect::<Vec<_>>(),
+            "law_num": law_num,
+        }),
+        ok: true,
+    }
+}
+
+fn validate(s: &str) -> Output {
+    let law_id = match LawId::from_id_str(s) {
+        Ok(law_id) => law_id,
+        Err(e) => return Output::error(s, &e),
+    };
+    let diagnostics: Vec<String> = law_id.validate().iter().map(|d| d.to_string()).collect();
+    let ok = diagnostics.is_empty();
+    let text = if ok {
+        format!("{s}: ok")
+    } else {
+        format!("{s}: invalid: {}", diagnostics.join("; "))
+    };
+    Output {
+        text,
+        json: json!({ "input": s, "valid": ok, "diagnostics": diagnostics }),
+        ok,
+    }
+}
+
+/// 法令番号から一意に定まらない場合は候補をすべて出力する
+fn to_id(law_num: &str) -> Output {
+    match LawId::candidates_from_law_num(law_num) {
+        Ok(candidates) => {
+            let ids: Vec<String> = candidates.iter().map(|id| id.to_id_str()).collect();
+            Output {
+                text: ids.join("\n"),
+                json: json!({ "input": law_num, "ids": ids }),
+                ok: true,
+            }
+        }
+        Err(e) => Output::error(law_num, &e),
+    }
+}
+
+fn to_num(s: &str, options: &LawNumOptions) -> Output {
+    let law_id = match LawId::from_id_str(s) {
+        Ok(law_id) => law_id,
+        Err(e) => return Output::error(s, &e),
+    };
+    match law_id.to_law_num_with(options) {
+        Some(law_num) => Output {
+            text: law_num.clone(),
+            json: json!({ "input": s, "law_num": law_num }),
+            ok: true,
+        },
+        None => Output {
+            text: format!("{s}: error: no law number for this law type"),
+            json: json!({ "input": s, "error": "no law number for this law type" }),
+            ok: false,
+        },
+    }
+}
+
+#[test]
+fn check_format_with_batch() {
+    let cli = Cli::try_parse_from([
+        "japanese-law-id",
+        "--format",
+        "json",
+        "to-id",
+        "令和五年法律第六十号",
+    ])
+    .unwrap();
+    assert_eq!(cli.output_format().unwrap(), Format::Json);
+    let cli = Cli::try_parse_from(["japanese-law-id", "batch", "--column", "id"]).unwrap();
+    assert!(cli.output_format().is_ok());
+    for args in [
+        [
+            "japanese-law-id",
+            "--format",
+            "json",
+            "batch",
+            "--column",
+            "id",
+        ],
+        [
+            "japanese-law-id",
+            "batch",
+            "--column",
+            "id",
+            "--format",
+            "text",
+        ],
+    ] {
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert_eq!(
+            cli.output_format().unwrap_err().kind(),
+            ErrorKind::ArgumentConflict
+        );
+    }
+}
+
+#[test]
+fn check_explain() {
+    let output = explain("505M60001024060");
+    assert!(output.ok);
+    assert_eq!(output.json["era"]["en"], "Reiwa");
+    assert_eq!(output.json["ad_year"], 2023);
+    assert_eq!(output.json["law_type"]["ja"], "府省令");
+    assert_eq!(output.json["issuers"].as_array().unwrap().len(), 3);
+    assert_eq!(output.json["issuers"][0]["ja"], "復興庁");
+    assert!(output.text.contains("Ministry of the Environment"));
+    assert!(!explain("505M6000102406").ok);
+}
+
+#[test]
+fn check_validate_and_convert() {
+    assert!(validate("322AC0000000067").ok);
+    assert!(!validate("199AC0000000001").ok);
+    assert!(!validate("322XX0000000067").ok);
+    assert_eq!(
+        to_id("令和五年法律第六十号").json["ids"],
+        json!(["505AC0000000060", "505AC1000000060", "505AC0100000060"])
+    );
+    let options = LawNumOptions::default();
+    assert_eq!(
+        to_num("325M50001000004", &options).text,
+        "昭和二十五年郵政省令第四号"
+    );
+    assert!(!to_num("321CONSTITUTION", &options).ok);
+}
@@ -0,0 +1,285 @@
+//! グレゴリオ暦の日付
+
+use crate::*;
+
+/// 日付
+///
+/// 内部は西暦（グレゴリオ暦）で管理し，存在しない日付は生成できない．
+/// 年，月，日の順に比較する
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "DateFields")
+)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub(crate) year: usize,
+    pub(crate) month: usize,
+    pub(crate) day: usize,
+}
+
+/// デシリアライズした検査前の`Date`
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct DateFields {
+    year: usize,
+    month: usize,
+    day: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DateFields> for Date {
+    type Error = LawIdError;
+    fn try_from(fields: DateFields) -> Result<Self, Self::Error> {
+        Date::new_ad(fields.year, fields.month, fields.day)
+    }
+}
+
+/// 曜日
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Weekday {
+    /// 月曜日
+    Monday,
+    /// 火曜日
+    Tuesday,
+    /// 水曜日
+    Wednesday,
+    /// 木曜日
+    Thursday,
+    /// 金曜日
+    Friday,
+    /// 土曜日
+    Saturday,
+    /// 日曜日
+    Sunday,
+}
+
+impl Weekday {
+    /// 「月」，「火」などの一文字の表記
+    pub fn to_text(self) -> &'static str {
+        match self {
+            Self::Monday => "月",
+            Self::Tuesday => "火",
+            Self::Wednesday => "水",
+            Self::Thursday => "木",
+            Self::Friday => "金",
+            Self::Saturday => "土",
+            Self::Sunday => "日",
+        }
+    }
+}
+
+/// 扱う西暦年の上限
+const MAX_YEAR: usize = 9999;
+
+/// 西暦年の月の日数
+///
+/// 存在しない月は0日とする
+pub(crate) const fn days_in_month(year: usize, month: usize) -> usize {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// 存在する日付かどうか
+const fn is_valid(year: usize, month: usize, day: usize) -> bool {
+    1 <= year && year <= MAX_YEAR && 1 <= day && day <= days_in_month(year, month)
+}
+
+/// 定数の表のための日付の生成
+///
+/// 存在しない日付はコンパイル時のエラーになる
+pub(crate) const fn ymd(year: usize, month: usize, day: usize) -> Date {
+    assert!(is_valid(year, month, day));
+    Date { year, month, day }
+}
+
+impl Date {
+    /// 和暦からの作成
+    ///
+    /// 明治5年までは旧暦（天保暦）の月日としてグレゴリオ暦に変換する．
+    /// 閏月の日付は`WarekiDate::new_leap_month`から変換する．
+    /// 存在しない日付や元号の期間外の日付は`LawIdError::InvalidDate`になる
+    pub fn new_wareki(era: Era, year: usize, month: usize, day: usize) -> Result<Self, LawIdError> {
+        WarekiDate::new(era, year, month, day).map(WarekiDate::to_date)
+    }
+
+    /// 西暦からの作成
+    ///
+    /// 存在しない日付や，1年から9999年の範囲外の日付は`LawIdError::InvalidDate`になる
+    pub fn new_ad(year: usize, month: usize, day: usize) -> Result<Self, LawIdError> {
+        if is_valid(year, month, day) {
+            Ok(Self { year, month, day })
+        } else {
+            Err(LawIdError::InvalidDate { position: 0 })
+        }
+    }
+
+    /// 西暦年の取得
+    pub fn get_ad_year(self) -> usize {
+        self.year
+    }
+
+    /// 和暦年の取得
+    pub fn gen_wareki_year(self) -> Result<Wareki, LawIdError> {
+        Wareki::from_ad(self.year, self.month, self.day)
+    }
+
+    pub fn get_month(self) -> usize {
+        self.month
+    }
+
+    pub fn get_day(self) -> usize {
+        self.day
+    }
+
+    /// 既定の`EliTemplate`のURIの日付までの部分（「/eli/2023/06/20/」）
+    pub fn joined_str(self) -> String {
+        format!(
+            "/eli/{:0>4}/{:0>2}/{:0>2}/",
+            self.year, self.month, self.day
+        )
+    }
+
+    /// 曜日
+    pub fn weekday(self) -> Weekday {
+        // 1970年1月1日は木曜日
+        match (self.to_days() + 3).rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    /// `days`日後の日付
+    ///
+    /// 扱える範囲を超える場合は`None`を返す
+    pub fn checked_add_days(self, days: i64) -> Option<Self> {
+        let n = self.to_days().checked_add(days)?;
+        let range = ymd(1, 1, 1).to_days()..=ymd(MAX_YEAR, 12, 31).to_days();
+        range.contains(&n).then(|| Self::from_days(n))
+    }
+
+    /// `days`日前の日付
+    ///
+    /// 扱える範囲を超える場合は`None`を返す
+    pub fn checked_sub_days(self, days: i64) -> Option<Self> {
+        self.checked_add_days(days.checked_neg()?)
+    }
+
+    /// `other`から数えた日数（「施行日 - 公布日」など）
+    ///
+    /// `other`の方が後の日付の場合は負になる
+    pub fn days_since(self, other: Self) -> i64 {
+        self.to_days() - other.to_days()
+    }
+
+    /// 1970年1月1日からの日数
+    pub(crate) fn to_days(self) -> i64 {
+        let (y, m, d) = (self.year as i64, self.month as i64, self.day as i64);
+        let y = if m <= 2 { y - 1 } else { y };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let doy = (153 * ((m + 9) % 12) + 2) / 5 + d - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    /// 1970年1月1日からの日数から生成
+    ///
+    /// 範囲外の日数かどうかは呼び出し側で確認する
+    pub(crate) fn from_days(n: i64) -> Self {
+        let z = n + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as usize,
+            month: month as usize,
+            day: day as usize,
+        }
+    }
+}
+
+/// 日付の差を日数で求める
+impl std::ops::Sub for Date {
+    type Output = i64;
+    fn sub(self, other: Self) -> i64 {
+        self.days_since(other)
+    }
+}
+
+#[test]
+fn check_date_gen() {
+    let d = Date::new_ad(1923, 6, 20).unwrap().gen_wareki_year();
+    assert_eq!(d, Ok(Wareki::new(Era::Taisho, 12)));
+    assert_eq!(
+        Date::new_ad(2023, 2, 31),
+        Err(LawIdError::InvalidDate { position: 0 })
+    );
+    assert!(Date::new_ad(2024, 2, 29).is_ok());
+    assert!(Date::new_ad(1900, 2, 29).is_err());
+    assert!(Date::new_ad(2000, 2, 29).is_ok());
+    assert!(Date::new_ad(2024, 0, 1).is_err());
+    assert!(Date::new_ad(0, 1, 1).is_err());
+    assert_eq!(
+        Date::new_wareki(Era::Reiwa, 5, 6, 16),
+        Date::new_ad(2023, 6, 16)
+    );
+    assert!(Date::new_wareki(Era::Showa, 64, 1, 8).is_err());
+}
+
+#[test]
+fn check_date_arithmetic() {
+    let date = |y, m, d| Date::new_ad(y, m, d).unwrap();
+    assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thursday);
+    assert_eq!(date(2019, 5, 1).weekday(), Weekday::Wednesday);
+    assert_eq!(date(1946, 11, 3).weekday(), Weekday::Sunday);
+    assert_eq!(
+        date(2024, 2, 28).checked_add_days(1),
+        Some(date(2024, 2, 29))
+    );
+    assert_eq!(
+        date(2024, 2, 28).checked_add_days(2),
+        Some(date(2024, 3, 1))
+    );
+    assert_eq!(
+        date(2024, 1, 1).checked_sub_days(1),
+        Some(date(2023, 12, 31))
+    );
+    assert_eq!(date(9999, 12, 31).checked_add_days(1), None);
+    assert_eq!(date(1, 1, 1).checked_sub_days(1), None);
+    assert_eq!(date(2024, 1, 1).checked_add_days(i64::MAX), None);
+    // 日本国憲法の公布日と施行日
+    assert_eq!(date(1947, 5, 3) - date(1946, 11, 3), 181);
+    assert_eq!(date(1946, 11, 3).days_since(date(1947, 5, 3)), -181);
+    assert!(date(1989, 1, 7) < date(1989, 1, 8));
+    assert!(date(2000, 12, 31) < date(2001, 1, 1));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_date_serde() {
+    let date = Date::new_ad(2023, 2, 28).unwrap();
+    let json = serde_json::to_string(&date).unwrap();
+    assert_eq!(json, r#"{"year":2023,"month":2,"day":28}"#);
+    assert_eq!(serde_json::from_str::<Date>(&json).unwrap(), date);
+    assert!(serde_json::from_str::<Date>(r#"{"year":2023,"month":2,"day":30}"#).is_err());
+    assert!(serde_json::from_str::<Date>(r#"{"year":0,"month":1,"day":1}"#).is_err());
+}
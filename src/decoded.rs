@@ -0,0 +1,212 @@
+//! 法令IDを展開した平坦な形式
+//!
+//! フロントエンドなどで法令IDを解析せずに表示するためのもので，`LawId`のシリアライズの形式とは独立している．
+//! 形式を変えるときは`DECODED_SCHEMA_VERSION`を上げ，`schema/`にその版のJSON Schemaを加える
+
+use crate::*;
+
+/// `DecodedLawId`の形式の版
+pub const DECODED_SCHEMA_VERSION: u32 = 1;
+
+/// 法令IDを展開したもの
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "schemars",
+    derive(schemars::JsonSchema),
+    schemars(extend("$id" = "urn:japanese_law_id:decoded_law_id:v1"))
+)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DecodedLawId {
+    /// 形式の版（`DECODED_SCHEMA_VERSION`）
+    #[cfg_attr(feature = "schemars", schemars(extend("const" = DECODED_SCHEMA_VERSION)))]
+    pub schema_version: u32,
+    /// 15文字の法令ID
+    pub id: String,
+    /// 元号のローマ字表記（「Showa」など）
+    pub era: String,
+    /// 元号（「昭和」など）
+    pub era_name: String,
+    /// 和暦の年
+    pub year: usize,
+    /// 西暦年
+    pub ad_year: usize,
+    /// 法令IDで種別を表す記号（「AC」や「M」など）
+    pub law_type: String,
+    /// 法令の種別の名称（「法律」など）
+    pub law_type_name: String,
+    /// 法令の種別の英語名（「Act」など）
+    pub law_type_english_name: String,
+    /// 法令番号の番号．憲法は空，人事院規則は分類，分類中の連番，改正の連番（改正規則でなければ0）の3つ，それ以外は1つ
+    #[cfg_attr(feature = "schemars", schemars(extend("maxItems" = 3)))]
+    pub numbers: Vec<usize>,
+    /// 府省令の区分（M1〜M6）の番号
+    pub ministry_period: Option<usize>,
+    /// 府省令を定める府・省（ビットの小さい順）
+    pub ministries: Vec<DecodedMinistry>,
+    /// 規則を定める機関
+    pub institution: Option<DecodedInstitution>,
+    /// 「昭和二十五年郵政省令第四号」のような法令番号
+    pub law_num: Option<String>,
+}
+
+/// 府・省
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DecodedMinistry {
+    /// 法令IDでのビット（1〜28）
+    pub bit: usize,
+    /// 法令番号に現れる名称（「郵政省令」など）
+    pub name: String,
+    /// 府省の名称（「郵政省」など）
+    pub agency_name: String,
+    /// 府省の英語名
+    pub english_name: String,
+}
+
+/// 規則を定める機関
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DecodedInstitution {
+    /// 法令IDでの機関番号
+    pub code: usize,
+    /// 法令番号に現れる名称（「会計検査院規則」など）
+    pub name: String,
+    /// 機関の名称（「会計検査院」など）
+    pub agency_name: String,
+    /// 機関の英語名
+    pub english_name: String,
+}
+
+fn decode_ministries<T: MinistryContents>(l: &MinistrySet<T>) -> Vec<DecodedMinistry> {
+    l.iter()
+        .map(|m| DecodedMinistry {
+            bit: m.to_int(),
+            name: String::from(m.name()),
+            agency_name: String::from(m.agency_name()),
+            english_name: String::from(m.english_name()),
+        })
+        .collect()
+}
+
+impl From<LawId> for DecodedLawId {
+    fn from(law_id: LawId) -> Self {
+        let era = law_id.wareki.get_era();
+        let law_type = &law_id.law_type;
+        let (ministry_period, ministries) = match law_type {
+            LawType::MinistryOrder { ministry, .. } => {
+                let ministries = match ministry {
+                    Ministry::M1(l) => decode_ministries(l),
+                    Ministry::M2(l) => decode_ministries(l),
+                    Ministry::M3(l) => decode_ministries(l),
+                    Ministry::M4(l) => decode_ministries(l),
+                    Ministry::M5(l) => decode_ministries(l),
+                    Ministry::M6(l) => decode_ministries(l),
+                };
+                (Some(ministry.period()), ministries)
+            }
+            _ => (None, Vec::new()),
+        };
+        let institution = match law_type {
+            LawType::Regulation { institution, .. } => Some(DecodedInstitution {
+                code: institution.to_int(),
+                name: String::from(institution.name()),
+                agency_name: String::from(institution.agency_name()),
+                english_name: String::from(institution.english_name()),
+            }),
+            _ => None,
+        };
+        Self {
+            schema_version: DECODED_SCHEMA_VERSION,
+            id: law_id.to_id_str(),
+            era: String::from(era.as_xml_str()),
+            era_name: era.to_text(),
+            year: law_id.wareki.get_year(),
+            ad_year: law_id.wareki.to_ad(),
+            law_type: String::from(law_type.code()),
+            law_type_name: String::from(law_type.name()),
+            law_type_english_name: String::from(law_type.english_name()),
+            numbers: match *law_type {
+                LawType::Jinjin {
+                    kind,
+                    kind_serial_number,
+                    amendment_serial_number,
+                } => vec![kind, kind_serial_number, amendment_serial_number],
+                _ => law_type.xml_nums(),
+            },
+            ministry_period,
+            ministries,
+            institution,
+            law_num: law_id.to_law_num(),
+        }
+    }
+}
+
+impl LawId {
+    /// 展開した平坦な形式にする
+    pub fn decode(&self) -> DecodedLawId {
+        DecodedLawId::from(*self)
+    }
+}
+
+impl DecodedLawId {
+    /// `id`の法令IDを解析する
+    pub fn law_id(&self) -> Result<LawId, LawIdError> {
+        LawId::from_id_str(&self.id)
+    }
+
+    /// この版の形式のJSON Schema
+    #[cfg(feature = "schemars")]
+    pub fn json_schema() -> schemars::Schema {
+        schemars::schema_for!(DecodedLawId)
+    }
+}
+
+#[test]
+fn check_decoded_law_id() {
+    let law_id = LawId::from_id_str("505M60001024060").unwrap();
+    let decoded = law_id.decode();
+    assert_eq!(decoded.era, "Reiwa");
+    assert_eq!(decoded.ad_year, 2023);
+    assert_eq!(decoded.law_type, "M");
+    assert_eq!(decoded.numbers, vec![60]);
+    assert_eq!(decoded.ministry_period, Some(6));
+    assert_eq!(
+        decoded
+            .ministries
+            .iter()
+            .map(|m| (m.bit, m.agency_name.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(3, "復興庁"), (6, "外務省"), (13, "環境省")]
+    );
+    assert_eq!(decoded.law_id(), Ok(law_id));
+    let decoded = LawId::from_id_str("410RJNJ14007000").unwrap().decode();
+    assert_eq!(decoded.numbers, vec![14, 7, 0]);
+    assert_eq!(decoded.institution, None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_decoded_law_id_json() {
+    let decoded = LawId::from_id_str("322AC0000000067").unwrap().decode();
+    let json = serde_json::to_value(&decoded).unwrap();
+    assert_eq!(json["schema_version"], 1);
+    assert_eq!(json["law_type_english_name"], "Act");
+    assert_eq!(json["ministries"], serde_json::json!([]));
+    assert_eq!(
+        serde_json::from_value::<DecodedLawId>(json).unwrap(),
+        decoded
+    );
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn check_decoded_law_id_schema() {
+    let schema = serde_json::to_string_pretty(&DecodedLawId::json_schema()).unwrap();
+    assert_eq!(
+        schema.trim_end(),
+        include_str!("../schema/decoded_law_id.v1.json").trim_end(),
+        "schema/decoded_law_id.v1.json is out of date"
+    );
+}
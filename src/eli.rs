@@ -0,0 +1,396 @@
+//! ELI（European Legislation Identifier）のURI
+//!
+//! ELIの詳細は <https://eur-lex.europa.eu/eli-register/about.html> を参照
+
+use crate::*;
+
+/// ELIのURIが表す法令
+///
+/// テンプレートで決まる部分の後に，版と言語が`/{version}/{language}`の形で続く
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Eli {
+    pub law_id: LawId,
+    /// 公布日
+    pub date: Date,
+    /// 版と言語
+    pub expression: Option<EliExpression>,
+}
+
+/// ELIのURIの末尾の版と言語
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct EliExpression {
+    /// 版（制定時の"orig"や，施行日の"20230401"など）
+    pub version: String,
+    /// 言語（"jpn"，"eng"など）
+    pub language: Option<String>,
+}
+
+impl Eli {
+    /// 法令IDと公布日から生成する
+    ///
+    /// 公布日の和暦の年が法令IDの年と異なる場合は`LawIdError::InvalidEli`になる
+    pub fn new(law_id: LawId, date: Date) -> Result<Self, LawIdError> {
+        if date.gen_wareki_year() != Ok(law_id.wareki) {
+            return Err(LawIdError::InvalidEli { position: 0 });
+        }
+        Ok(Self {
+            law_id,
+            date,
+            expression: None,
+        })
+    }
+
+    /// 版を指定する
+    pub fn with_version(mut self, version: &str) -> Self {
+        self.expression = Some(EliExpression {
+            version: version.to_string(),
+            language: None,
+        });
+        self
+    }
+
+    /// 版と言語を指定する
+    pub fn with_language(mut self, version: &str, language: &str) -> Self {
+        self.expression = Some(EliExpression {
+            version: version.to_string(),
+            language: Some(language.to_string()),
+        });
+        self
+    }
+
+    /// 既定のテンプレートでURIを生成する
+    pub fn to_uri(&self) -> String {
+        EliTemplate::default().to_uri(self)
+    }
+}
+
+impl LawType {
+    /// ELIのURIでの法令の種別
+    pub fn eli_type(&self) -> &'static str {
+        use LawType::*;
+        match self {
+            Constitution => "constitution",
+            Act { .. } => "act",
+            CabinetOrder { .. } => "cabinet_order",
+            ImperialOrder { .. } => "imperial_order",
+            DajokanFukoku { .. } => "dajokan_fukoku",
+            DajokanTasshi { .. } => "dajokan_tasshi",
+            DajokanHutatsu { .. } => "dajokan_hutatsu",
+            MinistryOrder { .. } => "ministerial_ordinance",
+            Jinjin { .. } => "npa_rule",
+            Regulation { .. } => "rule",
+            PrimeMinisterDecision { .. } => "pm_decision",
+        }
+    }
+}
+
+/// テンプレート中の置き換えられる箇所
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Field {
+    /// `{type}`：`LawType::eli_type`
+    Type,
+    /// `{year}`：公布日の西暦年（4桁）
+    Year,
+    /// `{month}`：公布日の月（2桁）
+    Month,
+    /// `{day}`：公布日の日（2桁）
+    Day,
+    /// `{id}`：15文字の法令ID
+    Id,
+}
+
+impl Field {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "type" => Some(Self::Type),
+            "year" => Some(Self::Year),
+            "month" => Some(Self::Month),
+            "day" => Some(Self::Day),
+            "id" => Some(Self::Id),
+            _ => None,
+        }
+    }
+
+    /// 値の文字数．`{type}`は可変長のため`None`
+    fn width(self) -> Option<usize> {
+        match self {
+            Self::Type => None,
+            Self::Year => Some(4),
+            Self::Month | Self::Day => Some(2),
+            Self::Id => Some(15),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Field(Field),
+}
+
+/// ELIのURIのテンプレート
+///
+/// `{type}`，`{year}`，`{month}`，`{day}`，`{id}`を値に置き換えてURIを作る．
+/// `{year}`，`{month}`，`{day}`，`{id}`は必ず含み，`{type}`の直後は固定の文字列である必要がある
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct EliTemplate {
+    template: String,
+    parts: Vec<Part>,
+}
+
+/// 既定のテンプレート
+const DEFAULT_TEMPLATE: &str = "/eli/{year}/{month}/{day}/{type}/{id}";
+
+/// `/eli/{year}/{month}/{day}/{type}/{id}`
+impl Default for EliTemplate {
+    fn default() -> Self {
+        Self::new(DEFAULT_TEMPLATE).unwrap()
+    }
+}
+
+impl EliTemplate {
+    /// 「https://example.jp/eli/jp/{type}/{year}/{month}/{day}/{id}」のようなテンプレートから生成
+    pub fn new(template: &str) -> Result<Self, LawIdError> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut rest = template;
+        while let Some(i) = rest.find(['{', '}']) {
+            let position = template.len() - rest.len() + i;
+            literal.push_str(&rest[..i]);
+            let close = rest[i..]
+                .find('}')
+                .filter(|_| rest[i..].starts_with('{'))
+                .ok_or(LawIdError::InvalidEliTemplate { position })?;
+            let field = Field::from_name(&rest[i + 1..i + close])
+                .ok_or(LawIdError::InvalidEliTemplate { position })?;
+            match parts.last() {
+                Some(Part::Field(Field::Type)) if literal.is_empty() => {
+                    return Err(LawIdError::InvalidEliTemplate { position });
+                }
+                _ => (),
+            }
+            if parts.contains(&Part::Field(field)) {
+                return Err(LawIdError::InvalidEliTemplate { position });
+            }
+            if !literal.is_empty() {
+                parts.push(Part::Literal(std::mem::take(&mut literal)));
+            }
+            parts.push(Part::Field(field));
+            rest = &rest[i + close + 1..];
+        }
+        literal.push_str(rest);
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+        let required = [Field::Year, Field::Month, Field::Day, Field::Id];
+        if required
+            .iter()
+            .any(|field| !parts.contains(&Part::Field(*field)))
+        {
+            return Err(LawIdError::InvalidEliTemplate {
+                position: template.len(),
+            });
+        }
+        Ok(Self {
+            template: template.to_string(),
+            parts,
+        })
+    }
+
+    /// テンプレートの文字列
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+
+    /// URIを生成する
+    pub fn to_uri(&self, eli: &Eli) -> String {
+        let mut uri = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Literal(s) => uri.push_str(s),
+                Part::Field(Field::Type) => uri.push_str(eli.law_id.law_type.eli_type()),
+                Part::Field(Field::Year) => uri.push_str(&format!("{:04}", eli.date.year)),
+                Part::Field(Field::Month) => uri.push_str(&format!("{:02}", eli.date.month)),
+                Part::Field(Field::Day) => uri.push_str(&format!("{:02}", eli.date.day)),
+                Part::Field(Field::Id) => uri.push_str(&eli.law_id.to_id_str()),
+            }
+        }
+        if let Some(expression) = &eli.expression {
+            uri.push('/');
+            uri.push_str(&expression.version);
+            if let Some(language) = &expression.language {
+                uri.push('/');
+                uri.push_str(language);
+            }
+        }
+        uri
+    }
+
+    /// URIを解析する
+    ///
+    /// `{type}`が法令IDの種別と異なる場合や，公布日と法令IDの年が合わない場合も`LawIdError::InvalidEli`になる
+    pub fn parse(&self, uri: &str) -> Result<Eli, LawIdError> {
+        let mut pos = 0;
+        let mut type_segment = None;
+        let (mut year, mut month, mut day, mut law_id) = (0, 0, 0, None);
+        for (i, part) in self.parts.iter().enumerate() {
+            let rest = &uri[pos..];
+            match part {
+                Part::Literal(s) => {
+                    if !rest.starts_with(s.as_str()) {
+                        return Err(LawIdError::InvalidEli { position: pos });
+                    }
+                    pos += s.len();
+                }
+                Part::Field(field) => {
+                    let len = match (field.width(), self.parts.get(i + 1)) {
+                        (Some(width), _) => width,
+                        (None, Some(Part::Literal(next))) => rest.find(next.as_str()).unwrap_or(0),
+                        (None, _) => rest.find('/').unwrap_or(rest.len()),
+                    };
+                    let value = rest
+                        .get(..len)
+                        .filter(|s| !s.is_empty())
+                        .ok_or(LawIdError::InvalidEli { position: pos })?;
+                    match field {
+                        Field::Type => type_segment = Some((value, pos)),
+                        Field::Year => year = parse_digits(uri, pos..pos + len)?,
+                        Field::Month => month = parse_digits(uri, pos..pos + len)?,
+                        Field::Day => day = parse_digits(uri, pos..pos + len)?,
+                        Field::Id => {
+                            law_id =
+                                Some((LawId::from_id_str(value).map_err(|e| e.offset(pos))?, pos))
+                        }
+                    }
+                    pos += len;
+                }
+            }
+        }
+        // 必須の置き換え箇所があることはテンプレートの生成時に確認している
+        let (law_id, id_pos) = law_id.unwrap();
+        if let Some((segment, position)) = type_segment {
+            if segment != law_id.law_type.eli_type() {
+                return Err(LawIdError::InvalidEli { position });
+            }
+        }
+        let date = Date::new_ad(year, month, day)
+            .map_err(|_| LawIdError::InvalidEli { position: id_pos })?;
+        let eli = Eli::new(law_id, date).map_err(|e| e.offset(id_pos))?;
+        let expression = parse_expression(uri, pos)?;
+        Ok(Eli { expression, ..eli })
+    }
+
+    /// URIを解析して法令IDを取り出す
+    pub fn parse_law_id(&self, uri: &str) -> Result<LawId, LawIdError> {
+        self.parse(uri).map(|eli| eli.law_id)
+    }
+}
+
+/// テンプレートの後に続く`/{version}/{language}`を解析する
+fn parse_expression(uri: &str, start: usize) -> Result<Option<EliExpression>, LawIdError> {
+    let rest = &uri[start..];
+    if rest.is_empty() {
+        return Ok(None);
+    }
+    let segments = rest
+        .strip_prefix('/')
+        .ok_or(LawIdError::InvalidEli { position: start })?;
+    let mut segments = segments.split('/');
+    let mut pos = start + 1;
+    let mut next_segment = || {
+        let segment = segments.next()?;
+        let position = pos;
+        pos += segment.len() + 1;
+        Some((segment, position))
+    };
+    let (version, position) = next_segment().unwrap();
+    if version.is_empty() {
+        return Err(LawIdError::InvalidEli { position });
+    }
+    let language = match next_segment() {
+        None => None,
+        Some(("", position)) => {
+            return Err(LawIdError::InvalidEli { position });
+        }
+        Some((language, _)) => Some(language.to_string()),
+    };
+    if let Some((_, position)) = next_segment() {
+        return Err(LawIdError::InvalidEli {
+            position: position - 1,
+        });
+    }
+    Ok(Some(EliExpression {
+        version: version.to_string(),
+        language,
+    }))
+}
+
+impl fmt::Display for EliTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.template)
+    }
+}
+
+impl FromStr for EliTemplate {
+    type Err = LawIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+#[test]
+fn check_eli() {
+    let law_id = LawId::from_id_str("322AC0000000067").unwrap();
+    let date = Date::new_ad(1947, 4, 17).unwrap();
+    let eli = Eli::new(law_id, date).unwrap();
+    assert_eq!(eli.to_uri(), "/eli/1947/04/17/act/322AC0000000067");
+    assert!(eli.to_uri().starts_with(&date.joined_str()));
+    let template =
+        EliTemplate::new("https://example.jp/eli/jp/{type}/{year}/{month}/{day}/{id}").unwrap();
+    let eli = eli.with_language("orig", "jpn");
+    let uri = "https://example.jp/eli/jp/act/1947/04/17/322AC0000000067/orig/jpn";
+    assert_eq!(template.to_uri(&eli), uri);
+    assert_eq!(template.parse(uri), Ok(eli.clone()));
+    assert_eq!(template.parse_law_id(uri), Ok(law_id));
+    let eli = eli.with_version("20230401");
+    assert_eq!(template.parse(&template.to_uri(&eli)), Ok(eli));
+    assert_eq!(
+        Eli::new(law_id, Date::new_ad(1948, 4, 17).unwrap()),
+        Err(LawIdError::InvalidEli { position: 0 })
+    );
+}
+
+#[test]
+fn check_eli_errors() {
+    let template = EliTemplate::default();
+    assert_eq!(
+        template.parse("/eli/1947/04/17/cabinet_order/322AC0000000067"),
+        Err(LawIdError::InvalidEli { position: 16 })
+    );
+    assert_eq!(
+        template.parse("/eli/1947/04/17/act/322XX0000000067"),
+        Err(LawIdError::UnknownLawType { position: 23 })
+    );
+    assert_eq!(
+        template.parse("/eli/1947/02/30/act/322AC0000000067"),
+        Err(LawIdError::InvalidEli { position: 20 })
+    );
+    assert_eq!(
+        template.parse("/eli/1947/04/17/act/322AC0000000067/orig/jpn/x"),
+        Err(LawIdError::InvalidEli { position: 44 })
+    );
+    assert_eq!(
+        EliTemplate::new("/eli/{year}/{month}/{id}"),
+        Err(LawIdError::InvalidEliTemplate { position: 24 })
+    );
+    assert_eq!(
+        EliTemplate::new("/eli/{year}/{month}/{day}/{kind}/{id}"),
+        Err(LawIdError::InvalidEliTemplate { position: 26 })
+    );
+    assert_eq!(
+        EliTemplate::new("/eli/{year}/{month}/{day}/{type}{id}"),
+        Err(LawIdError::InvalidEliTemplate { position: 32 })
+    );
+}
@@ -0,0 +1,273 @@
+//! 解析時のエラー
+
+use crate::{Institution, LawId, Ministry};
+use std::fmt;
+
+/// 法令IDや法令番号などの解析に失敗したときのエラー
+///
+/// `position`は入力文字列中で問題が見つかった箇所のバイトオフセット
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LawIdError {
+    /// ASCII以外の文字が含まれている
+    NonAscii { position: usize },
+    /// 文字列の長さが不正
+    InvalidLength {
+        position: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// 元号を表す数字が不正
+    InvalidEra { position: usize },
+    /// 数字であるべき箇所に数字以外の文字がある
+    InvalidDigit { position: usize },
+    /// 法令の種別を表す接頭辞が不明
+    UnknownLawType { position: usize },
+    /// 法律の立法の種類を表すフラグが不正
+    InvalidRippouType { position: usize },
+    /// 法令の効力を表すフラグが不正
+    InvalidEfficacy { position: usize },
+    /// 府省令の区分（M1〜M6）が不正
+    InvalidMinistryPeriod { position: usize },
+    /// 府省令の区分に存在しないビットが立っている
+    InvalidMinistryBit { position: usize, bit: usize },
+    /// 機関の番号が不正
+    UnknownInstitution { position: usize, code: usize },
+    /// 和暦の表記が不正
+    InvalidWareki { position: usize },
+    /// 年月日の表記が不正，または存在しない日付
+    InvalidDate { position: usize },
+    /// 元号の名称が不明
+    UnknownEraName { position: usize },
+    /// 府省・機関の名称が不明
+    UnknownMinistryName { position: usize },
+    /// 「令」などの種別が省略されているため，府・省が一意に定まらない
+    AmbiguousMinistry {
+        position: usize,
+        /// 考えられる府・省の法令番号に現れる名称
+        candidates: Vec<&'static str>,
+    },
+    /// 同じ名称の機関が複数あり，機関番号が一意に定まらない
+    AmbiguousInstitution {
+        position: usize,
+        /// 考えられる機関
+        candidates: Vec<Institution>,
+    },
+    /// 和暦に対応する府省令の区分が存在しない
+    MinistryPeriodNotFound { position: usize },
+    /// 年だけでは府省令の区分が一意に定まらない
+    AmbiguousMinistryPeriod {
+        position: usize,
+        /// 考えられる区分ごとの府省
+        candidates: Vec<Ministry>,
+    },
+    /// 法令番号の形式が不正
+    InvalidLawNum { position: usize },
+    /// 法令番号から法令IDが一意に定まらない
+    AmbiguousLawNum {
+        position: usize,
+        /// 考えられる法令ID
+        candidates: Vec<LawId>,
+    },
+    /// ELIのURIの形式が不正
+    InvalidEli { position: usize },
+    /// ELIのURIのテンプレートが不正
+    InvalidEliTemplate { position: usize },
+    /// 法令履歴IDの区切りが不正
+    InvalidRevisionId { position: usize },
+    /// 法令標準XMLの属性の値が不正
+    InvalidXmlAttribute {
+        position: usize,
+        /// 属性名
+        attribute: &'static str,
+    },
+    /// 法令標準XMLの属性と法令番号が食い違っている
+    XmlAttributeMismatch {
+        position: usize,
+        /// 属性名
+        attribute: &'static str,
+    },
+    /// 法令標準XMLの文書として読めない，または`<Law>`要素や`<LawNum>`要素が無い
+    InvalidXml { position: usize },
+    /// 法令を指すURLではない
+    InvalidUrl { position: usize },
+    /// URLが法令IDを含まない（日本法令索引のURLなど）
+    NoLawIdInUrl { position: usize },
+}
+
+impl LawIdError {
+    /// 問題が見つかった箇所のバイトオフセット
+    pub fn position(&self) -> usize {
+        use LawIdError::*;
+        match self {
+            NonAscii { position }
+            | InvalidLength { position, .. }
+            | InvalidEra { position }
+            | InvalidDigit { position }
+            | UnknownLawType { position }
+            | InvalidRippouType { position }
+            | InvalidEfficacy { position }
+            | InvalidMinistryPeriod { position }
+            | InvalidMinistryBit { position, .. }
+            | UnknownInstitution { position, .. }
+            | InvalidWareki { position }
+            | InvalidDate { position }
+            | UnknownEraName { position }
+            | UnknownMinistryName { position }
+            | AmbiguousMinistry { position, .. }
+            | AmbiguousInstitution { position, .. }
+            | MinistryPeriodNotFound { position }
+            | AmbiguousMinistryPeriod { position, .. }
+            | InvalidLawNum { position }
+            | AmbiguousLawNum { position, .. }
+            | InvalidEli { position }
+            | InvalidEliTemplate { position }
+            | InvalidRevisionId { position }
+            | InvalidXmlAttribute { position, .. }
+            | XmlAttributeMismatch { position, .. }
+            | InvalidXml { position }
+            | InvalidUrl { position }
+            | NoLawIdInUrl { position } => *position,
+        }
+    }
+
+    fn position_mut(&mut self) -> &mut usize {
+        use LawIdError::*;
+        match self {
+            NonAscii { position }
+            | InvalidLength { position, .. }
+            | InvalidEra { position }
+            | InvalidDigit { position }
+            | UnknownLawType { position }
+            | InvalidRippouType { position }
+            | InvalidEfficacy { position }
+            | InvalidMinistryPeriod { position }
+            | InvalidMinistryBit { position, .. }
+            | UnknownInstitution { position, .. }
+            | InvalidWareki { position }
+            | InvalidDate { position }
+            | UnknownEraName { position }
+            | UnknownMinistryName { position }
+            | AmbiguousMinistry { position, .. }
+            | AmbiguousInstitution { position, .. }
+            | MinistryPeriodNotFound { position }
+            | AmbiguousMinistryPeriod { position, .. }
+            | InvalidLawNum { position }
+            | AmbiguousLawNum { position, .. }
+            | InvalidEli { position }
+            | InvalidEliTemplate { position }
+            | InvalidRevisionId { position }
+            | InvalidXmlAttribute { position, .. }
+            | XmlAttributeMismatch { position, .. }
+            | InvalidXml { position }
+            | InvalidUrl { position }
+            | NoLawIdInUrl { position } => position,
+        }
+    }
+
+    /// 部分文字列を解析した結果のエラーを，元の文字列での位置に直す
+    pub(crate) fn offset(mut self, n: usize) -> Self {
+        *self.position_mut() += n;
+        self
+    }
+}
+
+impl fmt::Display for LawIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use LawIdError::*;
+        match self {
+            NonAscii { position } => write!(f, "non-ASCII character at byte {position}"),
+            InvalidLength {
+                position,
+                expected,
+                found,
+            } => write!(
+                f,
+                "invalid length at byte {position}: expected {expected} bytes, found {found}"
+            ),
+            InvalidEra { position } => write!(f, "invalid era digit at byte {position}"),
+            InvalidDigit { position } => write!(f, "expected a digit at byte {position}"),
+            UnknownLawType { position } => write!(f, "unknown law type at byte {position}"),
+            InvalidRippouType { position } => {
+                write!(f, "invalid legislation type flag at byte {position}")
+            }
+            InvalidEfficacy { position } => write!(f, "invalid efficacy flag at byte {position}"),
+            InvalidMinistryPeriod { position } => {
+                write!(f, "invalid ministry period at byte {position}")
+            }
+            InvalidMinistryBit { position, bit } => {
+                write!(f, "unexpected ministry bit {bit} at byte {position}")
+            }
+            UnknownInstitution { position, code } => {
+                write!(f, "unknown institution {code} at byte {position}")
+            }
+            InvalidWareki { position } => write!(f, "invalid wareki at byte {position}"),
+            InvalidDate { position } => write!(f, "invalid date at byte {position}"),
+            UnknownEraName { position } => write!(f, "unknown era name at byte {position}"),
+            UnknownMinistryName { position } => {
+                write!(f, "unknown ministry name at byte {position}")
+            }
+            AmbiguousMinistry {
+                position,
+                candidates,
+            } => write!(
+                f,
+                "ambiguous ministry name at byte {position}: {}",
+                candidates.join(", ")
+            ),
+            AmbiguousInstitution {
+                position,
+                candidates,
+            } => {
+                write!(f, "ambiguous institution name at byte {position}: ")?;
+                let codes: Vec<String> =
+                    candidates.iter().map(|i| i.to_int().to_string()).collect();
+                write!(f, "{}", codes.join(", "))
+            }
+            MinistryPeriodNotFound { position } => {
+                write!(f, "no ministry period matches the year at byte {position}")
+            }
+            AmbiguousMinistryPeriod {
+                position,
+                candidates,
+            } => {
+                write!(f, "ambiguous ministry period at byte {position}: ")?;
+                let ids: Vec<String> = candidates.iter().map(|m| m.to_id_str()).collect();
+                write!(f, "{}", ids.join(", "))
+            }
+            InvalidLawNum { position } => write!(f, "invalid law number at byte {position}"),
+            AmbiguousLawNum {
+                position,
+                candidates,
+            } => {
+                write!(f, "ambiguous law number at byte {position}: ")?;
+                let ids: Vec<String> = candidates.iter().map(|id| id.to_id_str()).collect();
+                write!(f, "{}", ids.join(", "))
+            }
+            InvalidEli { position } => write!(f, "invalid ELI URI at byte {position}"),
+            InvalidEliTemplate { position } => {
+                write!(f, "invalid ELI template at byte {position}")
+            }
+            InvalidRevisionId { position } => {
+                write!(f, "invalid law revision ID separator at byte {position}")
+            }
+            InvalidXmlAttribute {
+                position,
+                attribute,
+            } => write!(f, "invalid {attribute} attribute at byte {position}"),
+            XmlAttributeMismatch {
+                position,
+                attribute,
+            } => write!(
+                f,
+                "{attribute} attribute does not match the law number at byte {position}"
+            ),
+            InvalidXml { position } => write!(f, "invalid law XML at byte {position}"),
+            InvalidUrl { position } => write!(f, "invalid law URL at byte {position}"),
+            NoLawIdInUrl { position } => {
+                write!(f, "URL does not contain a law ID at byte {position}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LawIdError {}
@@ -0,0 +1,171 @@
+//! 明治5年12月2日（1872年12月31日）まで使われていた天保暦（旧暦）とグレゴリオ暦の変換
+//!
+//! 明治元年（慶応4年）から明治5年までの各月の朔日をグレゴリオ暦で持つ表による
+
+use crate::date::ymd;
+use crate::{Date, Era};
+
+/// 旧暦の月
+#[derive(Debug, Clone, Copy)]
+struct LunarMonth {
+    /// 明治の年
+    year: usize,
+    month: usize,
+    /// 閏月かどうか
+    leap_month: bool,
+    /// 朔日のグレゴリオ暦での日付
+    start: Date,
+}
+
+impl LunarMonth {
+    const fn new(
+        year: usize,
+        month: usize,
+        leap_month: bool,
+        ad_year: usize,
+        ad_month: usize,
+        ad_day: usize,
+    ) -> Self {
+        Self {
+            year,
+            month,
+            leap_month,
+            start: ymd(ad_year, ad_month, ad_day),
+        }
+    }
+}
+
+/// 明治元年1月から明治5年12月までの月
+const LUNAR_MONTHS: [LunarMonth; 62] = [
+    LunarMonth::new(1, 1, false, 1868, 1, 25),
+    LunarMonth::new(1, 2, false, 1868, 2, 23),
+    LunarMonth::new(1, 3, false, 1868, 3, 24),
+    LunarMonth::new(1, 4, false, 1868, 4, 23),
+    LunarMonth::new(1, 4, true, 1868, 5, 22),
+    LunarMonth::new(1, 5, false, 1868, 6, 20),
+    LunarMonth::new(1, 6, false, 1868, 7, 20),
+    LunarMonth::new(1, 7, false, 1868, 8, 18),
+    LunarMonth::new(1, 8, false, 1868, 9, 16),
+    LunarMonth::new(1, 9, false, 1868, 10, 16),
+    LunarMonth::new(1, 10, false, 1868, 11, 14),
+    LunarMonth::new(1, 11, false, 1868, 12, 14),
+    LunarMonth::new(1, 12, false, 1869, 1, 13),
+    LunarMonth::new(2, 1, false, 1869, 2, 11),
+    LunarMonth::new(2, 2, false, 1869, 3, 13),
+    LunarMonth::new(2, 3, false, 1869, 4, 12),
+    LunarMonth::new(2, 4, false, 1869, 5, 12),
+    LunarMonth::new(2, 5, false, 1869, 6, 10),
+    LunarMonth::new(2, 6, false, 1869, 7, 9),
+    LunarMonth::new(2, 7, false, 1869, 8, 8),
+    LunarMonth::new(2, 8, false, 1869, 9, 6),
+    LunarMonth::new(2, 9, false, 1869, 10, 5),
+    LunarMonth::new(2, 10, false, 1869, 11, 4),
+    LunarMonth::new(2, 11, false, 1869, 12, 3),
+    LunarMonth::new(2, 12, false, 1870, 1, 2),
+    LunarMonth::new(3, 1, false, 1870, 2, 1),
+    LunarMonth::new(3, 2, false, 1870, 3, 2),
+    LunarMonth::new(3, 3, false, 1870, 4, 1),
+    LunarMonth::new(3, 4, false, 1870, 5, 1),
+    LunarMonth::new(3, 5, false, 1870, 5, 30),
+    LunarMonth::new(3, 6, false, 1870, 6, 29),
+    LunarMonth::new(3, 7, false, 1870, 7, 28),
+    LunarMonth::new(3, 8, false, 1870, 8, 27),
+    LunarMonth::new(3, 9, false, 1870, 9, 25),
+    LunarMonth::new(3, 10, false, 1870, 10, 25),
+    LunarMonth::new(3, 10, true, 1870, 11, 23),
+    LunarMonth::new(3, 11, false, 1870, 12, 22),
+    LunarMonth::new(3, 12, false, 1871, 1, 21),
+    LunarMonth::new(4, 1, false, 1871, 2, 19),
+    LunarMonth::new(4, 2, false, 1871, 3, 21),
+    LunarMonth::new(4, 3, false, 1871, 4, 20),
+    LunarMonth::new(4, 4, false, 1871, 5, 19),
+    LunarMonth::new(4, 5, false, 1871, 6, 18),
+    LunarMonth::new(4, 6, false, 1871, 7, 18),
+    LunarMonth::new(4, 7, false, 1871, 8, 16),
+    LunarMonth::new(4, 8, false, 1871, 9, 15),
+    LunarMonth::new(4, 9, false, 1871, 10, 14),
+    LunarMonth::new(4, 10, false, 1871, 11, 13),
+    LunarMonth::new(4, 11, false, 1871, 12, 12),
+    LunarMonth::new(4, 12, false, 1872, 1, 11),
+    LunarMonth::new(5, 1, false, 1872, 2, 9),
+    LunarMonth::new(5, 2, false, 1872, 3, 9),
+    LunarMonth::new(5, 3, false, 1872, 4, 8),
+    LunarMonth::new(5, 4, false, 1872, 5, 7),
+    LunarMonth::new(5, 5, false, 1872, 6, 6),
+    LunarMonth::new(5, 6, false, 1872, 7, 6),
+    LunarMonth::new(5, 7, false, 1872, 8, 4),
+    LunarMonth::new(5, 8, false, 1872, 9, 3),
+    LunarMonth::new(5, 9, false, 1872, 10, 3),
+    LunarMonth::new(5, 10, false, 1872, 11, 1),
+    LunarMonth::new(5, 11, false, 1872, 12, 1),
+    LunarMonth::new(5, 12, false, 1872, 12, 30),
+];
+
+/// グレゴリオ暦に改められた最初の日（明治5年12月3日を明治6年1月1日とした）
+pub(crate) const GREGORIAN_START: Date = ymd(1873, 1, 1);
+
+/// 旧暦で表される和暦の年月日かどうか
+///
+/// 明治5年12月2日までが旧暦で，明治5年12月3日以降の日付は存在しない
+pub(crate) fn is_lunisolar(era: Era, year: usize) -> bool {
+    era == Era::Meiji && year <= 5
+}
+
+/// 旧暦の明治の年月日をグレゴリオ暦の日付に変換する
+///
+/// 存在しない日付の場合は`None`を返す
+pub(crate) fn to_gregorian(
+    year: usize,
+    month: usize,
+    leap_month: bool,
+    day: usize,
+) -> Option<Date> {
+    let i = LUNAR_MONTHS
+        .iter()
+        .position(|m| (m.year, m.month, m.leap_month) == (year, month, leap_month))?;
+    let start = LUNAR_MONTHS[i].start.to_days();
+    let next = LUNAR_MONTHS
+        .get(i + 1)
+        .map_or(GREGORIAN_START, |m| m.start)
+        .to_days();
+    if day == 0 || next < start + day as i64 {
+        None
+    } else {
+        Some(Date::from_days(start + day as i64 - 1))
+    }
+}
+
+/// グレゴリオ暦の日付を旧暦の明治の年，月，閏月かどうか，日に変換する
+///
+/// 旧暦の表の範囲外の場合は`None`を返す
+pub(crate) fn from_gregorian(date: Date) -> Option<(usize, usize, bool, usize)> {
+    if GREGORIAN_START <= date {
+        return None;
+    }
+    let m = LUNAR_MONTHS.iter().rev().find(|m| m.start <= date)?;
+    let day = (date.to_days() - m.start.to_days()) as usize + 1;
+    Some((m.year, m.month, m.leap_month, day))
+}
+
+#[test]
+fn check_lunisolar() {
+    assert_eq!(to_gregorian(1, 1, false, 1), Some(ymd(1868, 1, 25)));
+    assert_eq!(to_gregorian(1, 9, false, 8), Some(ymd(1868, 10, 23)));
+    assert_eq!(to_gregorian(3, 10, true, 5), Some(ymd(1870, 11, 27)));
+    assert_eq!(to_gregorian(5, 12, false, 2), Some(ymd(1872, 12, 31)));
+    assert_eq!(to_gregorian(5, 12, false, 3), None);
+    assert_eq!(to_gregorian(4, 10, true, 5), None);
+    assert_eq!(to_gregorian(1, 4, true, 30), None);
+    assert_eq!(from_gregorian(ymd(1868, 1, 24)), None);
+    assert_eq!(from_gregorian(ymd(1869, 1, 20)), Some((1, 12, false, 8)));
+    assert_eq!(from_gregorian(GREGORIAN_START), None);
+
+    // 旧暦の期間の毎日が往復で変わらないことを確かめる
+    let first = LUNAR_MONTHS[0].start.to_days();
+    for n in first..GREGORIAN_START.to_days() {
+        let date = Date::from_days(n);
+        let (year, month, leap_month, day) = from_gregorian(date).unwrap();
+        assert!((1..=30).contains(&day), "{date:?}");
+        assert_eq!(to_gregorian(year, month, leap_month, day), Some(date));
+    }
+}
@@ -0,0 +1,393 @@
+//! 「令和五年法律第六十号」のような法令番号の解析
+
+use crate::number::{format_number, format_positional_kansuji, parse_number};
+use crate::*;
+
+/// 人事院規則の番号の区切りとして使われる文字
+const JINJIN_SEPARATORS: [char; 6] = ['―', '－', '-', '‐', 'ー', '—'];
+
+/// 効力の種類を法令番号から判別できない法令で考えられる効力
+const ALL_EFFICACY: [LawEfficacy; 2] = [LawEfficacy::CabinetOrder, LawEfficacy::Law];
+
+/// 和暦より後の「法律第六十七号」のような法令の種別と番号
+static KIND_NUM_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new("^(?<kind>.+?)第(?<num>[0-9０-９〇一二三四五六七八九十百千]+)号$").unwrap()
+});
+
+/// 法令番号を生成するときの設定
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct LawNumOptions {
+    /// 年と番号の数字の表記
+    pub numeral: Numeral,
+    /// 1年を「元年」と表記するかどうか
+    pub gannen: bool,
+}
+
+/// 「令和元年法律第六十号」のように，漢数字で元年を使う
+impl Default for LawNumOptions {
+    fn default() -> Self {
+        Self {
+            numeral: Numeral::Kansuji,
+            gannen: true,
+        }
+    }
+}
+
+impl LawId {
+    /// 「昭和二十五年郵政省令第四号」のような法令番号を生成する
+    ///
+    /// 憲法と内閣総理大臣決定には法令番号が無いため`None`を返す
+    pub fn to_law_num(&self) -> Option<String> {
+        self.to_law_num_with(&LawNumOptions::default())
+    }
+
+    /// 数字の表記などを指定して法令番号を生成する
+    pub fn to_law_num_with(&self, options: &LawNumOptions) -> Option<String> {
+        use LawType::*;
+        let numeral = options.numeral;
+        let num_s = |num: usize| format!("第{}号", format_number(num, numeral));
+        let law_type_s = match &self.law_type {
+            Constitution | PrimeMinisterDecision { .. } => return None,
+            Act { num, .. } => format!("法律{}", num_s(*num)),
+            CabinetOrder { num, .. } => format!("政令{}", num_s(*num)),
+            ImperialOrder { num, .. } => format!("勅令{}", num_s(*num)),
+            DajokanFukoku { num, .. } => format!("太政官布告{}", num_s(*num)),
+            DajokanTasshi { num, .. } => format!("太政官達{}", num_s(*num)),
+            DajokanHutatsu { num, .. } => format!("太政官布達{}", num_s(*num)),
+            MinistryOrder { ministry, num } => format!("{}{}", ministry.to_name(), num_s(*num)),
+            Jinjin {
+                kind,
+                kind_serial_number,
+                amendment_serial_number,
+            } => {
+                let mut nums = vec![*kind, *kind_serial_number];
+                if *amendment_serial_number != 0 {
+                    nums.push(*amendment_serial_number);
+                }
+                let nums: Vec<String> = nums
+                    .into_iter()
+                    .map(|n| match numeral {
+                        Numeral::Kansuji => format_positional_kansuji(n),
+                        _ => format_number(n, numeral),
+                    })
+                    .collect();
+                format!("人事院規則{}", nums.join("―"))
+            }
+            Regulation { institution, num } => format!("{}{}", institution.name(), num_s(*num)),
+        };
+        let year = self.wareki.get_year();
+        let year_s = if options.gannen && year == 1 {
+            String::from("元")
+        } else {
+            format_number(year, numeral)
+        };
+        Some(format!(
+            "{}{year_s}年{law_type_s}",
+            self.wareki.get_era().to_text()
+        ))
+    }
+
+    /// 「令和五年法律第六十号」，「昭和25年郵政省令第4号」，「令和５年厚生労働省・農林水産省令第１号」などの法令番号から生成する
+    ///
+    /// 閣法と議員立法の区別や法律の効力を持つかどうかなど，
+    /// 法令番号からは法令IDが一意に定まらない場合は，候補を含んだ`LawIdError::AmbiguousLawNum`を返す
+    pub fn from_law_num(text: &str) -> Result<Self, LawIdError> {
+        let mut candidates = Self::candidates_from_law_num(text)?;
+        if candidates.len() == 1 {
+            Ok(candidates.remove(0))
+        } else {
+            Err(LawIdError::AmbiguousLawNum {
+                position: 0,
+                candidates,
+            })
+        }
+    }
+
+    /// 法令番号から考えられる法令IDをすべて返す
+    ///
+    /// 年や番号が法令IDの欄に収まらない（100年以上，第千号以上など）場合は`LawIdError::InvalidLawNum`になる
+    pub fn candidates_from_law_num(text: &str) -> Result<Vec<Self>, LawIdError> {
+        let (wareki, range) = Wareki::parse_text(text)?;
+        if range.start != 0 {
+            return Err(LawIdError::InvalidLawNum { position: 0 });
+        }
+        if wareki.get_year() > MAX_2_DIGITS {
+            return Err(LawIdError::InvalidLawNum {
+                position: wareki.get_era().to_text().len(),
+            });
+        }
+        let rest_start = range.end;
+        let rest = &text[rest_start..];
+
+        let law_types = if let Some(num_s) = rest.strip_prefix("人事院規則") {
+            vec![parse_jinjin(num_s).map_err(|e| e.offset(rest_start + "人事院規則".len()))?]
+        } else {
+            let caps = KIND_NUM_RE
+                .captures(rest)
+                .ok_or(LawIdError::InvalidLawNum {
+                    position: rest_start,
+                })?;
+            let num_m = caps.name("num").unwrap();
+            let num = parse_number(num_m.as_str())
+                .filter(|num| *num <= MAX_3_DIGITS)
+                .ok_or(LawIdError::InvalidLawNum {
+                    position: rest_start + num_m.start(),
+                })?;
+            let kind_end = rest_start + caps.name("kind").unwrap().end();
+            match &caps["kind"] {
+                "法律" => [RippouType::Kakuhou, RippouType::Syuin, RippouType::Sanin]
+                    .into_iter()
+                    .map(|rippou_type| LawType::Act { rippou_type, num })
+                    .collect(),
+                "政令" => cabinet_order_efficacy(wareki)
+                    .iter()
+                    .map(|&efficacy| LawType::CabinetOrder { efficacy, num })
+                    .collect(),
+                "勅令" => ALL_EFFICACY
+                    .into_iter()
+                    .map(|efficacy| LawType::ImperialOrder { efficacy, num })
+                    .collect(),
+                "太政官布告" => ALL_EFFICACY
+                    .into_iter()
+                    .map(|efficacy| LawType::DajokanFukoku { efficacy, num })
+                    .collect(),
+                "太政官達" => ALL_EFFICACY
+                    .into_iter()
+                    .map(|efficacy| LawType::DajokanTasshi { efficacy, num })
+                    .collect(),
+                "太政官布達" => ALL_EFFICACY
+                    .into_iter()
+                    .map(|efficacy| LawType::DajokanHutatsu { efficacy, num })
+                    .collect(),
+                kind => parse_issuer(&text[..kind_end], kind, rest_start, num)?,
+            }
+        };
+        Ok(law_types
+            .into_iter()
+            .map(|law_type| LawId { wareki, law_type })
+            .collect())
+    }
+}
+
+/// 政令のうち法律の効力を持つもの（ポツダム政令）は昭和22年から昭和27年にかけてのみ存在する
+fn cabinet_order_efficacy(wareki: Wareki) -> &'static [LawEfficacy] {
+    if wareki.get_era() == Era::Showa && (22..=27).contains(&wareki.get_year()) {
+        &ALL_EFFICACY
+    } else {
+        &ALL_EFFICACY[..1]
+    }
+}
+
+/// 「一四―七」や「九―八―一」のような人事院規則の番号を解析する
+///
+/// 分類は2桁，連番は3桁に収まる必要がある
+fn parse_jinjin(s: &str) -> Result<LawType, LawIdError> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for (i, sep) in s.match_indices(JINJIN_SEPARATORS) {
+        parts.push((start, &s[start..i]));
+        start = i + sep.len();
+    }
+    parts.push((start, &s[start..]));
+    let mut nums = Vec::new();
+    for (i, (position, part)) in parts.into_iter().enumerate() {
+        let max = if i == 0 { MAX_2_DIGITS } else { MAX_3_DIGITS };
+        let num = parse_number(part)
+            .filter(|num| *num <= max)
+            .ok_or(LawIdError::InvalidLawNum { position })?;
+        nums.push(num);
+    }
+    match nums[..] {
+        [kind, kind_serial_number] => Ok(LawType::Jinjin {
+            kind,
+            kind_serial_number,
+            amendment_serial_number: 0,
+        }),
+        [kind, kind_serial_number, amendment_serial_number] => Ok(LawType::Jinjin {
+            kind,
+            kind_serial_number,
+            amendment_serial_number,
+        }),
+        _ => Err(LawIdError::InvalidLawNum { position: 0 }),
+    }
+}
+
+/// 「厚生労働省令」や「会計検査院規則」のような府省令・機関の規則を解析する
+///
+/// `head`は和暦から法令の種別までの部分
+fn parse_issuer(
+    head: &str,
+    kind: &str,
+    kind_start: usize,
+    num: usize,
+) -> Result<Vec<LawType>, LawIdError> {
+    if !kind.ends_with('令') && !kind.ends_with("規則") {
+        return Err(LawIdError::UnknownLawType {
+            position: kind_start,
+        });
+    }
+    let mut law_types = Vec::new();
+    if let Some(issuer) = kind.strip_suffix("規則") {
+        if let Ok(institutions) = Institution::candidates_from_name(issuer) {
+            law_types.extend(
+                institutions
+                    .into_iter()
+                    .map(|institution| LawType::Regulation { institution, num }),
+            );
+        }
+    }
+    match Ministry::candidates_from_name(head) {
+        Ok(ministries) => law_types.extend(
+            ministries
+                .into_iter()
+                .map(|ministry| LawType::MinistryOrder { ministry, num }),
+        ),
+        Err(e) if law_types.is_empty() => return Err(e),
+        Err(_) => (),
+    }
+    Ok(law_types)
+}
+
+#[test]
+fn check_from_law_num() {
+    assert_eq!(
+        LawId::from_law_num("昭和二十五年郵政省令第四号"),
+        LawId::from_id_str("325M50001000004")
+    );
+    assert_eq!(
+        LawId::from_law_num("令和5年厚生労働省・農林水産省令第１号")
+            .unwrap()
+            .to_id_str(),
+        "505M60000300001"
+    );
+    assert_eq!(
+        LawId::from_law_num("昭和四十五年政令第八十九号"),
+        LawId::from_id_str("345CO0000000089")
+    );
+    assert_eq!(
+        LawId::from_law_num("昭和二十四年人事院規則一四―七"),
+        LawId::from_id_str("324RJNJ14007000")
+    );
+    assert_eq!(
+        LawId::from_law_num("昭和二十六年最高裁判所規則第九号"),
+        LawId::from_id_str("326R00000013009")
+    );
+    assert_eq!(
+        LawId::from_law_num("令和五年法律第六十号"),
+        Err(LawIdError::AmbiguousLawNum {
+            position: 0,
+            candidates: ["505AC0000000060", "505AC1000000060", "505AC0100000060"]
+                .iter()
+                .map(|s| LawId::from_id_str(s).unwrap())
+                .collect()
+        })
+    );
+    assert_eq!(
+        LawId::candidates_from_law_num("明治十五年太政官布告第三十七号")
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(
+        LawId::candidates_from_law_num("昭和二十年厚生省令第一号")
+            .unwrap()
+            .iter()
+            .map(|id| id.to_id_str())
+            .collect::<Vec<_>>(),
+        ["320M20000100001", "320M30000100001"]
+    );
+    assert_eq!(
+        LawId::candidates_from_law_num("昭和二十四年司法試験管理委員会規則第一号")
+            .unwrap()
+            .iter()
+            .map(|id| id.to_id_str())
+            .collect::<Vec<_>>(),
+        ["324R00000008001", "324R00000017001"]
+    );
+    assert_eq!(
+        LawId::from_law_num("令和五年法律六十号"),
+        Err(LawIdError::InvalidLawNum { position: 12 })
+    );
+    assert_eq!(
+        LawId::from_law_num("令和五年通達第一号"),
+        Err(LawIdError::UnknownLawType { position: 12 })
+    );
+    assert_eq!(
+        LawId::candidates_from_law_num("令和五年法律第千号"),
+        Err(LawIdError::InvalidLawNum { position: 21 })
+    );
+    assert_eq!(
+        LawId::candidates_from_law_num("令和五年法律第九百九十九号")
+            .unwrap()
+            .len(),
+        3
+    );
+    let board_of_audit = LawId::from_id_str("505R00000001001").unwrap();
+    assert!(
+        !LawId::candidates_from_law_num("令和五年偽会計検査院規則第一号")
+            .is_ok_and(|ids| ids.contains(&board_of_audit))
+    );
+    assert_eq!(
+        LawId::candidates_from_law_num("令和百年法律第一号"),
+        Err(LawIdError::InvalidLawNum { position: 6 })
+    );
+    assert_eq!(
+        LawId::candidates_from_law_num("令和五年人事院規則百―一"),
+        Err(LawIdError::InvalidLawNum { position: 27 })
+    );
+    assert_eq!(
+        LawId::candidates_from_law_num("令和五年人事院規則一―一―千"),
+        Err(LawIdError::InvalidLawNum { position: 39 })
+    );
+}
+
+#[test]
+fn check_to_law_num() {
+    let law_id = LawId::from_id_str("325M50001000004").unwrap();
+    assert_eq!(law_id.to_law_num().unwrap(), "昭和二十五年郵政省令第四号");
+    assert_eq!(
+        law_id
+            .to_law_num_with(&LawNumOptions {
+                numeral: Numeral::Arabic,
+                gannen: true
+            })
+            .unwrap(),
+        "昭和25年郵政省令第4号"
+    );
+    let law_id = LawId::from_id_str("501M60000300001").unwrap();
+    assert_eq!(
+        law_id.to_law_num().unwrap(),
+        "令和元年厚生労働省・農林水産省令第一号"
+    );
+    assert_eq!(
+        law_id
+            .to_law_num_with(&LawNumOptions {
+                numeral: Numeral::FullWidth,
+                gannen: false
+            })
+            .unwrap(),
+        "令和１年厚生労働省・農林水産省令第１号"
+    );
+    assert_eq!(
+        LawId::from_id_str("324RJNJ14007000")
+            .unwrap()
+            .to_law_num()
+            .unwrap(),
+        "昭和二十四年人事院規則一四―七"
+    );
+    assert_eq!(
+        LawId::from_id_str("321CONSTITUTION").unwrap().to_law_num(),
+        None
+    );
+    for s in [
+        "345AC0000000089",
+        "326R00000013009",
+        "505M60001024060",
+        "120DF0000000001",
+        "324RJNJ09008012",
+    ] {
+        let law_id = LawId::from_id_str(s).unwrap();
+        let candidates = LawId::candidates_from_law_num(&law_id.to_law_num().unwrap()).unwrap();
+        assert!(candidates.iter().any(|c| c.to_id_str() == s), "{s}");
+    }
+}
@@ -5,11 +5,137 @@ use kansuji::Kansuji;
 use regex::Regex;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+#[cfg(feature = "batch")]
+mod batch;
+mod date;
+mod decoded;
+mod eli;
+mod error;
+mod kyureki;
+mod law_num;
+mod ministry;
+mod number;
+mod packed;
+mod revision;
+mod scan;
+#[cfg(feature = "serde")]
+pub mod serde_format;
+mod url;
+mod validate;
+mod wareki_date;
+#[cfg(feature = "chrono")]
+mod with_chrono;
+#[cfg(feature = "jiff")]
+mod with_jiff;
+#[cfg(feature = "time")]
+mod with_time;
+#[cfg(feature = "xml")]
+mod with_xml;
+mod xml;
+
+#[cfg(feature = "batch")]
+pub use batch::{
+    BatchError, BatchFailure, BatchFormat, BatchOperation, BatchOptions, BatchRecord, BatchSummary,
+};
+pub use date::{Date, Weekday};
+use date::{days_in_month, ymd};
+pub use decoded::{DECODED_SCHEMA_VERSION, DecodedInstitution, DecodedLawId, DecodedMinistry};
+pub use eli::{Eli, EliExpression, EliTemplate};
+pub use error::LawIdError;
+pub use law_num::LawNumOptions;
+pub use ministry::{
+    Institution, M1Ministry, M2Ministry, M3Ministry, M4Ministry, M5Ministry, M6Ministry,
+    MinistryContents, MinistrySet, MinistrySetIter,
+};
+pub use number::Numeral;
+pub use revision::LawRevisionId;
+pub use scan::{LawIdMatch, MatchStyle, find_law_ids};
+pub use url::{EgovApiEndpoint, LawFileType, LawTarget, LawUrl};
+pub use validate::Diagnostic;
+pub use wareki_date::WarekiDate;
+pub use xml::{LawXmlAttributes, XmlLawType};
+
+/// `FromStr`と`Display`を実装した型に，文字列との相互変換を実装する
+macro_rules! impl_str_conversions {
+    ($t:ty) => {
+        impl TryFrom<&str> for $t {
+            type Error = LawIdError;
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        impl TryFrom<String> for $t {
+            type Error = LawIdError;
+            fn try_from(s: String) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        impl From<$t> for String {
+            fn from(v: $t) -> Self {
+                v.to_string()
+            }
+        }
+    };
+}
+pub(crate) use impl_str_conversions;
+
+/// ASCIIのみで構成され，指定された長さであるかを確認する
+fn check_id_chars(s: &str, expected: usize) -> Result<(), LawIdError> {
+    if let Some(position) = s.bytes().position(|b| !b.is_ascii()) {
+        return Err(LawIdError::NonAscii { position });
+    }
+    if s.len() != expected {
+        return Err(LawIdError::InvalidLength {
+            position: s.len().min(expected),
+            expected,
+            found: s.len(),
+        });
+    }
+    Ok(())
+}
+
+/// 法令IDの2桁の欄（年，人事院規則の分類など）に入る値の上限
+pub(crate) const MAX_2_DIGITS: usize = 99;
+
+/// 法令IDの3桁の欄（法令番号の番号など）に入る値の上限
+pub(crate) const MAX_3_DIGITS: usize = 999;
+
+/// 法令IDの4桁の欄（内閣総理大臣決定の連番）に入る値の上限
+pub(crate) const MAX_4_DIGITS: usize = 9999;
+
+/// ASCIIの文字列`s`の`range`の範囲を10進数として解析する
+///
+/// `usize`に収まらない場合は最初にあふれた桁の位置で`LawIdError::InvalidDigit`になる
+fn parse_digits(s: &str, range: std::ops::Range<usize>) -> Result<usize, LawIdError> {
+    let start = range.start;
+    let mut n: usize = 0;
+    for (i, b) in s.as_bytes()[range].iter().enumerate() {
+        if !b.is_ascii_digit() {
+            return Err(LawIdError::InvalidDigit {
+                position: start + i,
+            });
+        }
+        n = n
+            .checked_mul(10)
+            .and_then(|n| n.checked_add((b - b'0') as usize))
+            .ok_or(LawIdError::InvalidDigit {
+                position: start + i,
+            })?;
+    }
+    Ok(n)
+}
 
 /// 元号
 /// 現在の法体系が始まった明治以降を扱う
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Era {
     /// 明治
     Meiji,
@@ -23,16 +149,83 @@ pub enum Era {
     Reiwa,
 }
 
+/// 元号の期間
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EraBoundary {
+    pub era: Era,
+    /// 元号が適用される最初の日
+    pub start: Date,
+    /// 改元の詔書や政令が公布された日
+    pub proclaimed: Date,
+    /// 元号が適用される最後の日
+    /// 令和は終了していないため`None`
+    pub end: Option<Date>,
+}
+
+/// 明治以降の元号の期間（グレゴリオ暦）
+///
+/// 明治は明治元年10月23日（慶応4年9月8日）の改元の詔により，
+/// 慶応4年1月1日（1868年1月25日）に遡って適用された．
+/// 大正と昭和は天皇の崩御の当日に改元されたため，前の元号の最後の日はその前日とする
+pub const ERA_BOUNDARIES: [EraBoundary; 5] = [
+    EraBoundary {
+        era: Era::Meiji,
+        start: ymd(1868, 1, 25),
+        proclaimed: ymd(1868, 10, 23),
+        end: Some(ymd(1912, 7, 29)),
+    },
+    EraBoundary {
+        era: Era::Taisho,
+        start: ymd(1912, 7, 30),
+        proclaimed: ymd(1912, 7, 30),
+        end: Some(ymd(1926, 12, 24)),
+    },
+    EraBoundary {
+        era: Era::Showa,
+        start: ymd(1926, 12, 25),
+        proclaimed: ymd(1926, 12, 25),
+        end: Some(ymd(1989, 1, 7)),
+    },
+    EraBoundary {
+        era: Era::Heisei,
+        start: ymd(1989, 1, 8),
+        proclaimed: ymd(1989, 1, 7),
+        end: Some(ymd(2019, 4, 30)),
+    },
+    EraBoundary {
+        era: Era::Reiwa,
+        start: ymd(2019, 5, 1),
+        proclaimed: ymd(2019, 4, 1),
+        end: None,
+    },
+];
+
 impl Era {
-    /// 開始した年月日を整数で出す
-    fn start(self) -> usize {
-        match self {
-            Self::Meiji => 18681023,
-            Self::Taisho => 19120729,
-            Self::Showa => 19261225,
-            Self::Heisei => 19890108,
-            Self::Reiwa => 20190501,
-        }
+    /// 元号の期間
+    pub fn boundary(self) -> &'static EraBoundary {
+        &ERA_BOUNDARIES[self.to_number() - 1]
+    }
+
+    /// 日付がこの元号の期間内かどうか
+    pub fn contains(self, date: Date) -> bool {
+        let boundary = self.boundary();
+        boundary.start <= date && boundary.end.is_none_or(|end| date <= end)
+    }
+
+    /// 日付の元号を求める
+    ///
+    /// 明治より前の日付や存在しない日付は`LawIdError::InvalidDate`になる
+    pub fn from_date(date: Date) -> Result<Self, LawIdError> {
+        date.gen_wareki_year().map(|wareki| wareki.era)
+    }
+
+    /// 元号の最後の年
+    /// 令和は終了していないため`None`
+    pub fn last_year(self) -> Option<usize> {
+        self.boundary()
+            .end
+            .map(|end| end.get_ad_year() - self.start_year())
     }
 
     /// 計算の基点となる開始年 - 1
@@ -47,27 +240,15 @@ impl Era {
         }
     }
 
-    /// 終了した年月日を整数で出す
-    /// 令和は終了していないため，usize::MAX
-    fn end(self) -> usize {
-        match self {
-            Self::Meiji => 19120728,
-            Self::Taisho => 19261224,
-            Self::Showa => 19890107,
-            Self::Heisei => 20190431,
-            Self::Reiwa => usize::MAX,
-        }
-    }
-
     /// 文字列から生成
-    pub fn from_text(text: &str) -> Option<Self> {
+    pub fn from_text(text: &str) -> Result<Self, LawIdError> {
         match text {
-            "明治" => Some(Self::Meiji),
-            "大正" => Some(Self::Taisho),
-            "昭和" => Some(Self::Showa),
-            "平成" => Some(Self::Heisei),
-            "令和" => Some(Self::Reiwa),
-            _ => None,
+            "明治" => Ok(Self::Meiji),
+            "大正" => Ok(Self::Taisho),
+            "昭和" => Ok(Self::Showa),
+            "平成" => Ok(Self::Heisei),
+            "令和" => Ok(Self::Reiwa),
+            _ => Err(LawIdError::UnknownEraName { position: 0 }),
         }
     }
 
@@ -107,36 +288,44 @@ impl Era {
 }
 
 /// 和暦（平成5年，令和元年など）
+///
+/// 元号，年の順に比較する
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Wareki {
     era: Era,
     year: usize,
 }
 
+/// 「平成五年」，「平成5年」，「平成５年」などの和暦
+static WAREKI_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new("(?<era>明治|大正|昭和|平成|令和)((?<year_gan>元)|(?<year_kansuji>[一|二|三|四|五|六|七|八|九|十|百]+)|(?<year_num>[1|2|3|4|5|6|7|8|9|0]+)|(?<year_num_zen>[１|２|３|４|５|６|７|８|９|０]+))年").unwrap()
+});
+
 impl Wareki {
     pub fn new(era: Era, year: usize) -> Self {
         Self { era, year }
     }
 
     /// 西暦からの作成
-    pub fn from_ad(year: usize, month: usize, day: usize) -> Self {
-        use Era::*;
-        let t = year * 10000 + month * 100 + day;
-        let (era, year) = if (Meiji.start()..=Meiji.end()).contains(&t) {
-            (Meiji, year - Meiji.start_year())
-        } else if (Taisho.start()..=Taisho.end()).contains(&t) {
-            (Taisho, year - Taisho.start_year())
-        } else if (Showa.start()..=Showa.end()).contains(&t) {
-            (Showa, year - Showa.start_year())
-        } else if (Heisei.start()..=Heisei.end()).contains(&t) {
-            (Heisei, year - Heisei.start_year())
-        } else if Reiwa.start() <= t {
-            (Reiwa, year - Reiwa.start_year())
-        } else {
-            unreachable!()
-        };
-        Self { era, year }
+    ///
+    /// 存在しない日付や明治より前の日付は`LawIdError::InvalidDate`になる
+    pub fn from_ad(year: usize, month: usize, day: usize) -> Result<Self, LawIdError> {
+        let date = Date::new_ad(year, month, day)?;
+        if date < kyureki::GREGORIAN_START {
+            // 明治5年までは旧暦の年とする
+            return kyureki::from_gregorian(date)
+                .map(|(year, ..)| Self::new(Era::Meiji, year))
+                .ok_or(LawIdError::InvalidDate { position: 0 });
+        }
+        let boundary = ERA_BOUNDARIES
+            .iter()
+            .find(|b| b.era.contains(date))
+            .ok_or(LawIdError::InvalidDate { position: 0 })?;
+        Ok(Self {
+            era: boundary.era,
+            year: year - boundary.era.start_year(),
+        })
     }
 
     /// 西暦での年を生成
@@ -145,10 +334,22 @@ impl Wareki {
     }
 
     /// 「大正元年」，「平成五年」，「平成5年」，「平成５年」などのテキストから生成
-    pub fn from_text(text: &str) -> Option<Self> {
-        let re = Regex::new("(?<era>明治|大正|昭和|平成|令和)((?<year_gan>元)|(?<year_kansuji>[一|二|三|四|五|六|七|八|九|十|百]+)|(?<year_num>[1|2|3|4|5|6|7|8|9|0]+)|(?<year_num_zen>[１|２|３|４|５|６|７|８|９|０]+))年").unwrap();
-        re.captures(text).and_then(|caps| {
-            let era = Era::from_text(&caps["era"]).unwrap();
+    pub fn from_text(text: &str) -> Result<Self, LawIdError> {
+        Self::parse_text(text).map(|(wareki, _)| wareki)
+    }
+
+    /// テキスト中の和暦を解析し，和暦とそれが現れた範囲を返す
+    fn parse_text(text: &str) -> Result<(Self, std::ops::Range<usize>), LawIdError> {
+        let caps = WAREKI_RE
+            .captures(text)
+            .ok_or(LawIdError::InvalidWareki { position: 0 })?;
+        let range = caps.get(0).unwrap().range();
+        let era_m = caps.name("era").unwrap();
+        let era = Era::from_text(era_m.as_str()).map_err(|e| e.offset(era_m.start()))?;
+        let year_err = LawIdError::InvalidWareki {
+            position: era_m.end(),
+        };
+        let year_opt = {
             if caps.name("year_gan").is_some() {
                 Some(Self { era, year: 1 })
             } else if let Some(s) = &caps.name("year_kansuji") {
@@ -179,7 +380,8 @@ impl Wareki {
             } else {
                 None
             }
-        })
+        };
+        year_opt.map(|wareki| (wareki, range)).ok_or(year_err)
     }
     pub fn get_era(&self) -> Era {
         self.era
@@ -189,139 +391,161 @@ impl Wareki {
     }
 }
 
+impl fmt::Display for Era {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
+
+/// 「令和5年」，「令和元年」の形式で出力する
+impl fmt::Display for Wareki {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.year == 1 {
+            write!(f, "{}元年", self.era)
+        } else {
+            write!(f, "{}{}年", self.era, self.year)
+        }
+    }
+}
+
+/// 文字列全体が「令和5年」などの和暦の表記である必要がある
+impl FromStr for Wareki {
+    type Err = LawIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (wareki, range) = Self::parse_text(s)?;
+        if range.start != 0 {
+            Err(LawIdError::InvalidWareki { position: 0 })
+        } else if range.end != s.len() {
+            Err(LawIdError::InvalidWareki {
+                position: range.end,
+            })
+        } else {
+            Ok(wareki)
+        }
+    }
+}
+
+impl_str_conversions!(Wareki);
+
 #[test]
 fn check_wareki_parse() {
     assert_eq!(
         Wareki::from_text("大正元年"),
-        Some(Wareki {
+        Ok(Wareki {
             era: Era::Taisho,
             year: 1
         })
     );
     assert_eq!(
         Wareki::from_text("大正五年"),
-        Some(Wareki {
+        Ok(Wareki {
             era: Era::Taisho,
             year: 5
         })
     );
     assert_eq!(
         Wareki::from_text("大正5年"),
-        Some(Wareki {
+        Ok(Wareki {
             era: Era::Taisho,
             year: 5
         })
     );
     assert_eq!(
         Wareki::from_text("大正５年"),
-        Some(Wareki {
+        Ok(Wareki {
             era: Era::Taisho,
             year: 5
         })
     );
     assert_eq!(
         Wareki::from_text("昭和十五年"),
-        Some(Wareki {
+        Ok(Wareki {
             era: Era::Showa,
             year: 15
         })
     );
     assert_eq!(
         Wareki::from_text("昭和15年"),
-        Some(Wareki {
+        Ok(Wareki {
             era: Era::Showa,
             year: 15
         })
     );
     assert_eq!(
         Wareki::from_text("昭和１５年"),
-        Some(Wareki {
+        Ok(Wareki {
             era: Era::Showa,
             year: 15
         })
     );
 }
 
-/// 日付
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub struct Date {
-    // 内部は西暦で管理
-    year: usize,
-    month: usize,
-    day: usize,
-}
-
-impl Date {
-    /// 和暦からの作成
-    pub fn new_wareki(era: Era, year: usize, month: usize, day: usize) -> Self {
-        Self {
-            year: Wareki::new(era, year).to_ad(),
-            month,
-            day,
-        }
-    }
-
-    /// 西暦からの作成
-    pub fn new_ad(year: usize, month: usize, day: usize) -> Self {
-        Self { year, month, day }
-    }
-
-    /// 西暦年の取得
-    pub fn get_ad_year(self) -> usize {
-        self.year
-    }
-
-    /// 和暦年の取得
-    pub fn gen_wareki_year(self) -> Wareki {
-        Wareki::from_ad(self.year, self.month, self.day)
-    }
-
-    pub fn get_month(self) -> usize {
-        self.month
-    }
-
-    pub fn get_day(self) -> usize {
-        self.day
-    }
-
-    pub fn joined_str(self) -> String {
-        format!(
-            "/eli/{:0>4}/{:0>2}/{:0>2}/",
-            self.year, self.month, self.day
-        )
-    }
-}
-
 #[test]
-fn check_date_gen() {
-    let d = Date::new_ad(1923, 06, 20).gen_wareki_year();
+fn check_era_boundaries() {
+    use Era::*;
+    let from_ad = |y, m, d| Wareki::from_ad(y, m, d);
     assert_eq!(
-        d,
-        Wareki {
-            era: Era::Taisho,
-            year: 12
+        from_ad(1868, 1, 24),
+        Err(LawIdError::InvalidDate { position: 0 })
+    );
+    assert_eq!(from_ad(1868, 1, 25), Ok(Wareki::new(Meiji, 1)));
+    assert_eq!(from_ad(1868, 10, 23), Ok(Wareki::new(Meiji, 1)));
+    assert_eq!(from_ad(1912, 7, 29), Ok(Wareki::new(Meiji, 45)));
+    assert_eq!(from_ad(1912, 7, 30), Ok(Wareki::new(Taisho, 1)));
+    assert_eq!(from_ad(1926, 12, 24), Ok(Wareki::new(Taisho, 15)));
+    assert_eq!(from_ad(1926, 12, 25), Ok(Wareki::new(Showa, 1)));
+    assert_eq!(from_ad(1989, 1, 7), Ok(Wareki::new(Showa, 64)));
+    assert_eq!(from_ad(1989, 1, 8), Ok(Wareki::new(Heisei, 1)));
+    assert_eq!(from_ad(2019, 4, 30), Ok(Wareki::new(Heisei, 31)));
+    assert_eq!(from_ad(2019, 5, 1), Ok(Wareki::new(Reiwa, 1)));
+    assert!(from_ad(2019, 4, 31).is_err());
+    assert!(from_ad(2023, 2, 29).is_err());
+    assert!(from_ad(2024, 2, 29).is_ok());
+    assert!(from_ad(2024, 13, 1).is_err());
+    assert!(from_ad(2024, 1, 0).is_err());
+    assert_eq!(Meiji.last_year(), Some(45));
+    assert_eq!(Taisho.last_year(), Some(15));
+    assert_eq!(Showa.last_year(), Some(64));
+    assert_eq!(Heisei.last_year(), Some(31));
+    assert_eq!(Reiwa.last_year(), None);
+
+    // 明治元年から毎日，ちょうど一つの元号に属し，元号の期間が連続していることを確かめる
+    let mut prev: Option<Wareki> = None;
+    for year in 1868..=2100 {
+        for month in 1..=12 {
+            for day in 1..=days_in_month(year, month) {
+                let date = ymd(year, month, day);
+                if date < ERA_BOUNDARIES[0].start {
+                    continue;
+                }
+                let eras: Vec<Era> = ERA_BOUNDARIES
+                    .iter()
+                    .map(|b| b.era)
+                    .filter(|e| e.contains(date))
+                    .collect();
+                assert_eq!(eras.len(), 1, "{date:?}");
+                let wareki = Wareki::from_ad(year, month, day).unwrap();
+                if kyureki::GREGORIAN_START <= date {
+                    assert_eq!(wareki.to_ad(), year);
+                }
+                if let Some(prev) = prev {
+                    assert!(
+                        prev == wareki
+                            || prev.era == wareki.era && prev.year + 1 == wareki.year
+                            || prev.era.to_number() + 1 == wareki.era.to_number()
+                                && wareki.year == 1,
+                        "{date:?}"
+                    );
+                }
+                prev = Some(wareki);
+            }
         }
-    )
-}
-
-impl PartialOrd for Date {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for Date {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let s = self.year * 10000 + self.month * 100 + self.day;
-        let o = other.year * 10000 + other.month * 100 + other.day;
-        s.cmp(&o)
     }
 }
 
 /// 法律の立法の種類
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum RippouType {
     /// 閣法
     Kakuhou,
@@ -333,7 +557,7 @@ pub enum RippouType {
 
 /// 法律の効力の種類
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum LawEfficacy {
     /// 政令
     CabinetOrder,
@@ -341,1390 +565,275 @@ pub enum LawEfficacy {
     Law,
 }
 
-/// 府・省に共通化させる
-pub trait MinistryContents: Sized {
-    /// 事前に用意されている府・省令のビットに変換する．
-    /// <https://laws.e-gov.go.jp/file/LawIdNamingConvention.pdf>の9ページ参照．
-    fn to_int(&self) -> usize;
-    /// 事前に用意されている府・省令のビットから戻す
-    /// <https://laws.e-gov.go.jp/file/LawIdNamingConvention.pdf>の9ページ参照．
-    fn from_int(n: usize) -> Option<Self>;
-    /// 区分の開始年月日
-    fn start() -> Date;
-    /// 区分の終了年月日
-    fn end() -> Date;
-    /// 該当する年代かどうかの判定
-    fn applicable(date: Date) -> bool {
-        Self::start() <= date && date <= Self::end()
-    }
-    /// 和暦から該当する年代かどうかの判定
-    fn applicable_wareki(wareki: Wareki) -> bool {
-        Self::start().year <= wareki.to_ad() && wareki.to_ad() <= Self::end().year
-    }
-    /// 複数省庁管轄の法令の法令IDを計算する
-    fn to_id_str(l: &[Self]) -> String {
-        let mut n = 0;
-        for u in l.iter().map(|v| v.to_int() as u32) {
-            n |= 2_u32.pow(u - 1);
-        }
-        format!("{:07X}", n)
-    }
-    /// 複数省庁管轄の法令の法令ID文字列のうち，省庁を表す箇所から担当省庁を計算する
-    fn from_id_str(byte_s: &str) -> Result<Vec<Self>, String> {
-        let chars = byte_s.chars();
-        let mut v = Vec::new();
-        for (i, c) in chars.enumerate() {
-            if c == '1' {
-                let n = 28 - i;
-                if let Some(t) = Self::from_int(n) {
-                    v.push(t);
-                } else {
-                    return Err(format!("unexpected flag: {n}"));
-                }
-            } else if c == '0' {
-            } else {
-                return Err(format!("unexpected char: {c}"));
-            }
-        }
-        Ok(v)
-    }
-
-    /// 「厚生労働省令」や「厚生労働省・農林水産省令」などから導き出す
-    fn from_name(name: &str) -> Vec<Self>;
+/// 府・省
+///
+/// 区分，法令IDでのビット列の順に比較する
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(remote = "Self")
+)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Ministry {
+    /// 1869年7月8日〜1943年10月31日
+    M1(MinistrySet<M1Ministry>),
+    /// 1943年11月1日〜1945年11月30日
+    M2(MinistrySet<M2Ministry>),
+    /// 1945年12月1日〜1947年5月2日
+    M3(MinistrySet<M3Ministry>),
+    /// 1947年5月3日〜1949年5月31日
+    M4(MinistrySet<M4Ministry>),
+    /// 1949年6月1日〜2001年1月5日
+    M5(MinistrySet<M5Ministry>),
+    /// 2001年1月6日〜
+    M6(MinistrySet<M6Ministry>),
 }
 
-/// M1時（1869年7月8日〜1943年10月31日）での府・省
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub enum M1Ministry {
-    /// 閣令
-    CabinetOrder,
-    /// 宮内省令
-    ImperialHouseholdOrdinance,
-    /// 大東亜省令
-    GreaterEastAsiaMinisterialOrdinance,
-    /// 内務省令
-    MinistryOfTheInteriorOrdinance,
-    /// 司法省令
-    MinistryOfJusticeOrdinance,
-    /// 外務省令
-    MinistryOfForeignAffairsOrdinance,
-    /// 大蔵省令
-    MinistryOfFinanceOrdinance,
-    /// 文部省令
-    MinistryOfEducationOrdinance,
-    /// 厚生省令
-    MinistryOfHealthAndWelfareOrdinance,
-    /// 農商務省令
-    MinistryOfAgricultureAndCommerceOrdinance,
-    /// 商工省令
-    MinistryOfCommerceAndIndustryOrdinance,
-    /// 鉄道省令
-    RailwayMinisterialOrdinance,
-    /// 逓信省令
-    MinistryOfCommunicationsOrdinance,
-    /// 陸軍省令（甲）
-    MinistryOfTheArmyOrdinanceA,
-    ///海軍省令
-    NavyMinisterialOrdinance,
-    /// 陸軍省令（乙）
-    MinistryOfTheArmyOrdinanceB,
-    /// 農林省令
-    MinistryOfAgricultureAndForestryOrdinance,
-    /// 拓殖務省令
-    MinistryOfLandDevelopmentOrdinanceA,
-    /// 拓務省令
-    MinistryOfLandDevelopmentOrdinanceB,
-    /// 農商務省令臨
-    MinistryOfAgricultureAndCommerceOrdinanceTemporary,
-    /// 司法省令（丙）
-    /// 例：明治十九年度以降科料罰金徴収及民事刑事其他一時預リ金取扱方（明治19年4月7日司法省令丙第1号）<https://hourei.ndl.go.jp/#/detail?lawId=0000000249&searchDiv=1&current=1>
-    MinistryOfJusticeOrdinanceHei,
-}
+/// 「令和五年厚生労働省・農林水産省令」のような和暦の付いた府省令の名称
+static MINISTRY_NAME_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?<wareki>(明治|大正|昭和|平成|令和)[元|一|二|三|四|五|六|七|八|九|十|百|1|2|3|4|5|6|7|8|9|0|１|２|３|４|５|６|７|８|９|０]+)年((?<month>[一|二|三|四|五|六|七|八|九|十|百|1|2|3|4|5|6|7|8|9|0|１|２|３|４|５|６|７|８|９|０]+)月)?((?<day>[一|二|三|四|五|六|七|八|九|十|百|1|2|3|4|5|6|7|8|9|0|１|２|３|４|５|６|７|８|９|０]+)日)?(?<ministry>.+)$").unwrap()
+});
 
-impl MinistryContents for M1Ministry {
-    fn to_int(&self) -> usize {
-        use M1Ministry::*;
+impl Ministry {
+    pub fn to_id_str(&self) -> String {
         match self {
-            CabinetOrder => 1,
-            ImperialHouseholdOrdinance => 2,
-            GreaterEastAsiaMinisterialOrdinance => 3,
-            MinistryOfTheInteriorOrdinance => 4,
-            MinistryOfJusticeOrdinance => 5,
-            MinistryOfForeignAffairsOrdinance => 6,
-            MinistryOfFinanceOrdinance => 7,
-            MinistryOfEducationOrdinance => 8,
-            MinistryOfHealthAndWelfareOrdinance => 9,
-            MinistryOfAgricultureAndCommerceOrdinance => 10,
-            MinistryOfCommerceAndIndustryOrdinance => 11,
-            RailwayMinisterialOrdinance => 12,
-            MinistryOfCommunicationsOrdinance => 13,
-            MinistryOfTheArmyOrdinanceA => 14,
-            NavyMinisterialOrdinance => 15,
-            MinistryOfTheArmyOrdinanceB => 16,
-            MinistryOfAgricultureAndForestryOrdinance => 17,
-            MinistryOfLandDevelopmentOrdinanceA => 18,
-            MinistryOfLandDevelopmentOrdinanceB => 19,
-            MinistryOfAgricultureAndCommerceOrdinanceTemporary => 20,
-            MinistryOfJusticeOrdinanceHei => 21,
+            Self::M1(l) => format!("M1{}", l.to_id_str()),
+            Self::M2(l) => format!("M2{}", l.to_id_str()),
+            Self::M3(l) => format!("M3{}", l.to_id_str()),
+            Self::M4(l) => format!("M4{}", l.to_id_str()),
+            Self::M5(l) => format!("M5{}", l.to_id_str()),
+            Self::M6(l) => format!("M6{}", l.to_id_str()),
+        }
+    }
+
+    /// 「M60001024」のような府省令を表す9文字から生成
+    pub fn from_id_str(s: &str) -> Result<Self, LawIdError> {
+        check_id_chars(s, 9)?;
+        if &s[0..1] != "M" {
+            return Err(LawIdError::UnknownLawType { position: 0 });
+        }
+        let hex_s = &s[2..9];
+        let ministry = match &s[1..2] {
+            "1" => Ministry::M1(MinistrySet::from_id_str(hex_s).map_err(|e| e.offset(2))?),
+            "2" => Ministry::M2(MinistrySet::from_id_str(hex_s).map_err(|e| e.offset(2))?),
+            "3" => Ministry::M3(MinistrySet::from_id_str(hex_s).map_err(|e| e.offset(2))?),
+            "4" => Ministry::M4(MinistrySet::from_id_str(hex_s).map_err(|e| e.offset(2))?),
+            "5" => Ministry::M5(MinistrySet::from_id_str(hex_s).map_err(|e| e.offset(2))?),
+            "6" => Ministry::M6(MinistrySet::from_id_str(hex_s).map_err(|e| e.offset(2))?),
+            _ => return Err(LawIdError::InvalidMinistryPeriod { position: 1 }),
+        };
+        Ok(ministry)
+    }
+
+    /// 日付が属するM1〜M6の区分の番号
+    pub fn period_of_date(date: Date) -> Option<usize> {
+        [
+            M1Ministry::applicable(date),
+            M2Ministry::applicable(date),
+            M3Ministry::applicable(date),
+            M4Ministry::applicable(date),
+            M5Ministry::applicable(date),
+            M6Ministry::applicable(date),
+        ]
+        .iter()
+        .position(|&b| b)
+        .map(|i| i + 1)
+    }
+
+    /// 和暦の年が含まれるM1〜M6の区分の番号
+    ///
+    /// 昭和20年（1945年）のように年の途中で区分が変わる年では複数の区分を返す
+    pub fn periods_of_wareki(wareki: Wareki) -> Vec<usize> {
+        [
+            M1Ministry::applicable_wareki(wareki),
+            M2Ministry::applicable_wareki(wareki),
+            M3Ministry::applicable_wareki(wareki),
+            M4Ministry::applicable_wareki(wareki),
+            M5Ministry::applicable_wareki(wareki),
+            M6Ministry::applicable_wareki(wareki),
+        ]
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| **b)
+        .map(|(i, _)| i + 1)
+        .collect()
+    }
+
+    /// M1〜M6の区分の番号を指定して，「厚生労働省・農林水産省令」のような名称から生成する
+    pub fn from_name_in_period(name: &str, period: usize) -> Result<Self, LawIdError> {
+        let ministry = match period {
+            1 => Ministry::M1(MinistrySet::from_name(name)?),
+            2 => Ministry::M2(MinistrySet::from_name(name)?),
+            3 => Ministry::M3(MinistrySet::from_name(name)?),
+            4 => Ministry::M4(MinistrySet::from_name(name)?),
+            5 => Ministry::M5(MinistrySet::from_name(name)?),
+            6 => Ministry::M6(MinistrySet::from_name(name)?),
+            _ => return Err(LawIdError::InvalidMinistryPeriod { position: 0 }),
+        };
+        Ok(ministry)
+    }
+
+    /// 公布日を指定して，「厚生労働省・農林水産省令」のような名称から生成する
+    pub fn from_name_at(name: &str, date: Date) -> Result<Self, LawIdError> {
+        let period =
+            Self::period_of_date(date).ok_or(LawIdError::MinistryPeriodNotFound { position: 0 })?;
+        Self::from_name_in_period(name, period)
+    }
+
+    /// 「令和五年厚生労働省・農林水産省令」のような和暦の付いた名称から考えられるものをすべて返す
+    ///
+    /// 「昭和二十年十二月一日厚生省令」のように月日まで含まれている場合は，その日付から区分を一つに定める．
+    /// 年だけの場合は，その年に含まれる区分のうち名称が存在するものをすべて返す
+    pub fn candidates_from_name(name: &str) -> Result<Vec<Self>, LawIdError> {
+        let caps = MINISTRY_NAME_RE
+            .captures(name)
+            .ok_or(LawIdError::UnknownMinistryName { position: 0 })?;
+        let ministry_m = caps.name("ministry").unwrap();
+        let wareki_m = caps.name("wareki").unwrap();
+        // 「年」まで含めて和暦として解析する
+        let wareki = Wareki::from_text(&name[wareki_m.start()..wareki_m.end() + "年".len()])
+            .map_err(|e| e.offset(wareki_m.start()))?;
+        let month = caps
+            .name("month")
+            .and_then(|m| number::parse_number(m.as_str()));
+        let day = caps
+            .name("day")
+            .and_then(|m| number::parse_number(m.as_str()));
+        let periods = match (month, day) {
+            (Some(month), Some(day)) => {
+                let date = Date::new_wareki(wareki.get_era(), wareki.get_year(), month, day)
+                    .map_err(|e| e.offset(wareki_m.start()))?;
+                Self::period_of_date(date).into_iter().collect()
+            }
+            _ => Self::periods_of_wareki(wareki),
+        };
+        if periods.is_empty() {
+            return Err(LawIdError::MinistryPeriodNotFound {
+                position: wareki_m.start(),
+            });
+        }
+        let mut candidates = Vec::new();
+        let mut error = None;
+        for period in periods {
+            match Self::from_name_in_period(ministry_m.as_str(), period) {
+                Ok(ministry) => candidates.push(ministry),
+                Err(e) => {
+                    error.get_or_insert(e.offset(ministry_m.start()));
+                }
+            }
         }
-    }
-
-    fn from_int(n: usize) -> Option<Self> {
-        use M1Ministry::*;
-        match n {
-            1 => Some(CabinetOrder),
-            2 => Some(ImperialHouseholdOrdinance),
-            3 => Some(GreaterEastAsiaMinisterialOrdinance),
-            4 => Some(MinistryOfTheInteriorOrdinance),
-            5 => Some(MinistryOfJusticeOrdinance),
-            6 => Some(MinistryOfForeignAffairsOrdinance),
-            7 => Some(MinistryOfFinanceOrdinance),
-            8 => Some(MinistryOfEducationOrdinance),
-            9 => Some(MinistryOfHealthAndWelfareOrdinance),
-            10 => Some(MinistryOfAgricultureAndCommerceOrdinance),
-            11 => Some(MinistryOfCommerceAndIndustryOrdinance),
-            12 => Some(RailwayMinisterialOrdinance),
-            13 => Some(MinistryOfCommunicationsOrdinance),
-            14 => Some(MinistryOfTheArmyOrdinanceA),
-            15 => Some(NavyMinisterialOrdinance),
-            16 => Some(MinistryOfTheArmyOrdinanceB),
-            17 => Some(MinistryOfAgricultureAndForestryOrdinance),
-            18 => Some(MinistryOfLandDevelopmentOrdinanceA),
-            19 => Some(MinistryOfLandDevelopmentOrdinanceB),
-            20 => Some(MinistryOfAgricultureAndCommerceOrdinanceTemporary),
-            21 => Some(MinistryOfJusticeOrdinanceHei),
-            _ => None,
+        match error {
+            Some(e) if candidates.is_empty() => Err(e),
+            _ => Ok(candidates),
         }
     }
 
-    fn start() -> Date {
-        Date::new_ad(1869, 7, 8)
-    }
-
-    fn end() -> Date {
-        Date::new_ad(1943, 10, 31)
-    }
-
-    fn from_name(name: &str) -> Vec<Self> {
-        let mut v = Vec::new();
-        if name.contains("閣") {
-            v.push(Self::CabinetOrder)
-        }
-        if name.contains("宮内省") {
-            v.push(Self::ImperialHouseholdOrdinance)
-        }
-        if name.contains("大東亜省") {
-            v.push(Self::GreaterEastAsiaMinisterialOrdinance)
-        }
-        if name.contains("内務省") {
-            v.push(Self::MinistryOfTheInteriorOrdinance)
-        }
-        if name.contains("司法省") {
-            v.push(Self::MinistryOfJusticeOrdinance)
-        }
-        if name.contains("外務省") {
-            v.push(Self::MinistryOfForeignAffairsOrdinance)
-        }
-        if name.contains("大蔵省") {
-            v.push(Self::MinistryOfFinanceOrdinance)
-        }
-        if name.contains("文部省") {
-            v.push(Self::MinistryOfEducationOrdinance)
-        }
-        if name.contains("厚生省") {
-            v.push(Self::MinistryOfHealthAndWelfareOrdinance)
-        }
-        if name.contains("農商務省") {
-            v.push(Self::MinistryOfAgricultureAndCommerceOrdinance)
-        }
-        if name.contains("商工省") {
-            v.push(Self::MinistryOfCommerceAndIndustryOrdinance)
-        }
-        if name.contains("鉄道省") {
-            v.push(Self::RailwayMinisterialOrdinance)
-        }
-        if name.contains("逓信省") {
-            v.push(Self::MinistryOfCommunicationsOrdinance)
-        }
-        if name.contains("陸軍省") && name.contains("甲") {
-            v.push(Self::MinistryOfTheArmyOrdinanceA)
-        }
-        if name.contains("海軍省") {
-            v.push(Self::NavyMinisterialOrdinance)
-        }
-        if name.contains("陸軍省") && name.contains("乙") {
-            v.push(Self::MinistryOfTheArmyOrdinanceB)
-        }
-        if name.contains("農林省") {
-            v.push(Self::MinistryOfAgricultureAndForestryOrdinance)
-        }
-        if name.contains("拓殖務省") {
-            v.push(Self::MinistryOfLandDevelopmentOrdinanceA)
-        }
-        if name.contains("拓務省") {
-            v.push(Self::MinistryOfLandDevelopmentOrdinanceB)
-        }
-        if name.contains("農商務省") && name.contains("臨") {
-            v.push(Self::MinistryOfAgricultureAndCommerceOrdinanceTemporary)
-        }
-        if name.contains("司法省") && name.contains("丙") {
-            v.push(Self::MinistryOfJusticeOrdinanceHei)
+    /// 「令和五年厚生労働省・農林水産省令」のような和暦の付いた名称から，和暦に該当する区分で生成する
+    ///
+    /// 区分の変わる年で区分が一つに定まらない場合は，候補を含んだ`LawIdError::AmbiguousMinistryPeriod`を返す
+    pub fn from_name(name: &str) -> Result<Self, LawIdError> {
+        let mut candidates = Self::candidates_from_name(name)?;
+        if candidates.len() == 1 {
+            Ok(candidates.remove(0))
+        } else {
+            Err(LawIdError::AmbiguousMinistryPeriod {
+                position: 0,
+                candidates,
+            })
         }
-        v
     }
-}
-
-/// M2時（1943年11月1日〜1945年11月31日）での府・省
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub enum M2Ministry {
-    /// 閣令
-    CabinetOrder,
-    /// 宮内省令
-    ImperialHouseholdOrdinance,
-    /// 大東亜省令
-    GreaterEastAsiaMinisterialOrdinance,
-    /// 内務省令
-    MinistryOfTheInteriorOrdinance,
-    /// 司法省令
-    MinistryOfJusticeOrdinance,
-    /// 外務省令
-    MinistryOfForeignAffairsOrdinance,
-    /// 大蔵省令
-    MinistryOfFinanceOrdinance,
-    /// 文部省令
-    MinistryOfEducationOrdinance,
-    /// 厚生省令
-    MinistryOfHealthAndWelfareOrdinance,
-    /// 農商務省令
-    MinistryOfAgricultureAndCommerceOrdinance,
-    /// 商工省令
-    MinistryOfCommerceAndIndustryOrdinance,
-    /// 運輸省令
-    MinistryOfTransportOrdinance,
-    /// 運輸通信省令
-    MinistryOfTransportAndCommunicationsOrdinance,
-    /// 陸軍省令（甲）
-    MinistryOfTheArmyOrdinanceA,
-    ///海軍省令
-    NavyMinisterialOrdinance,
-    /// 軍需省令
-    OrdinanceOfTheMinistryOfMunitions,
-    /// 農林省令
-    MinistryOfAgricultureAndForestryOrdinance,
-}
 
-impl MinistryContents for M2Ministry {
-    fn to_int(&self) -> usize {
-        use M2Ministry::*;
+    /// 「厚生労働省・農林水産省令」のような法令番号に現れる名称を生成する
+    pub fn to_name(&self) -> String {
         match self {
-            CabinetOrder => 1,
-            ImperialHouseholdOrdinance => 2,
-            GreaterEastAsiaMinisterialOrdinance => 3,
-            MinistryOfTheInteriorOrdinance => 4,
-            MinistryOfJusticeOrdinance => 5,
-            MinistryOfForeignAffairsOrdinance => 6,
-            MinistryOfFinanceOrdinance => 7,
-            MinistryOfEducationOrdinance => 8,
-            MinistryOfHealthAndWelfareOrdinance => 9,
-            MinistryOfAgricultureAndCommerceOrdinance => 10,
-            MinistryOfCommerceAndIndustryOrdinance => 11,
-            MinistryOfTransportOrdinance => 12,
-            MinistryOfTransportAndCommunicationsOrdinance => 13,
-            MinistryOfTheArmyOrdinanceA => 14,
-            NavyMinisterialOrdinance => 15,
-            OrdinanceOfTheMinistryOfMunitions => 16,
-            MinistryOfAgricultureAndForestryOrdinance => 17,
-        }
-    }
-
-    fn from_int(n: usize) -> Option<Self> {
-        use M2Ministry::*;
-        match n {
-            1 => Some(CabinetOrder),
-            2 => Some(ImperialHouseholdOrdinance),
-            3 => Some(GreaterEastAsiaMinisterialOrdinance),
-            4 => Some(MinistryOfTheInteriorOrdinance),
-            5 => Some(MinistryOfJusticeOrdinance),
-            6 => Some(MinistryOfForeignAffairsOrdinance),
-            7 => Some(MinistryOfFinanceOrdinance),
-            8 => Some(MinistryOfEducationOrdinance),
-            9 => Some(MinistryOfHealthAndWelfareOrdinance),
-            10 => Some(MinistryOfAgricultureAndCommerceOrdinance),
-            11 => Some(MinistryOfCommerceAndIndustryOrdinance),
-            12 => Some(MinistryOfTransportOrdinance),
-            13 => Some(MinistryOfTransportAndCommunicationsOrdinance),
-            14 => Some(MinistryOfTheArmyOrdinanceA),
-            15 => Some(NavyMinisterialOrdinance),
-            16 => Some(OrdinanceOfTheMinistryOfMunitions),
-            17 => Some(MinistryOfAgricultureAndForestryOrdinance),
-            _ => None,
+            Self::M1(l) => l.joined_name(),
+            Self::M2(l) => l.joined_name(),
+            Self::M3(l) => l.joined_name(),
+            Self::M4(l) => l.joined_name(),
+            Self::M5(l) => l.joined_name(),
+            Self::M6(l) => l.joined_name(),
         }
     }
 
-    fn start() -> Date {
-        Date::new_ad(1943, 11, 1)
-    }
-
-    fn end() -> Date {
-        Date::new_ad(1945, 11, 30)
-    }
-
-    fn from_name(name: &str) -> Vec<Self> {
-        let mut v = Vec::new();
-        if name.contains("閣") {
-            v.push(Self::CabinetOrder)
-        }
-        if name.contains("宮内省") {
-            v.push(Self::ImperialHouseholdOrdinance)
+    /// 担当する府省の「厚生労働省」のような名称（ビットの小さい順）
+    pub fn agency_names(&self) -> Vec<&'static str> {
+        fn names<T: MinistryContents>(l: &MinistrySet<T>) -> Vec<&'static str> {
+            l.iter().map(|m| m.agency_name()).collect()
         }
-        if name.contains("大東亜省") {
-            v.push(Self::GreaterEastAsiaMinisterialOrdinance)
-        }
-        if name.contains("内務省") {
-            v.push(Self::MinistryOfTheInteriorOrdinance)
-        }
-        if name.contains("司法省") {
-            v.push(Self::MinistryOfJusticeOrdinance)
-        }
-        if name.contains("外務省") {
-            v.push(Self::MinistryOfForeignAffairsOrdinance)
-        }
-        if name.contains("大蔵省") {
-            v.push(Self::MinistryOfFinanceOrdinance)
-        }
-        if name.contains("文部省") {
-            v.push(Self::MinistryOfEducationOrdinance)
-        }
-        if name.contains("厚生省") {
-            v.push(Self::MinistryOfHealthAndWelfareOrdinance)
-        }
-        if name.contains("農商務省") {
-            v.push(Self::MinistryOfAgricultureAndCommerceOrdinance)
-        }
-        if name.contains("商工省") {
-            v.push(Self::MinistryOfCommerceAndIndustryOrdinance)
-        }
-        if name.contains("運輸省") {
-            v.push(Self::MinistryOfTransportOrdinance)
-        }
-        if name.contains("運輸通信省") {
-            v.push(Self::MinistryOfTransportAndCommunicationsOrdinance)
-        }
-        if name.contains("陸軍省") && name.contains("甲") {
-            v.push(Self::MinistryOfTheArmyOrdinanceA)
-        }
-        if name.contains("海軍省") {
-            v.push(Self::NavyMinisterialOrdinance)
-        }
-        if name.contains("軍需省") {
-            v.push(Self::OrdinanceOfTheMinistryOfMunitions)
-        }
-        if name.contains("農林省") {
-            v.push(Self::MinistryOfAgricultureAndForestryOrdinance)
+        match self {
+            Self::M1(l) => names(l),
+            Self::M2(l) => names(l),
+            Self::M3(l) => names(l),
+            Self::M4(l) => names(l),
+            Self::M5(l) => names(l),
+            Self::M6(l) => names(l),
         }
-        v
     }
-}
 
-/// M3時（1945年12月1日〜1947年5月2日）での府・省
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub enum M3Ministry {
-    /// 閣令
-    CabinetOrder,
-    /// 宮内省令
-    ImperialHouseholdOrdinance,
-    /// 経済安定本部令
-    EconomicStabilityHeadquartersOrdinance,
-    /// 内務省令
-    MinistryOfTheInteriorOrdinance,
-    /// 司法省令
-    MinistryOfJusticeOrdinance,
-    /// 外務省令
-    MinistryOfForeignAffairsOrdinance,
-    /// 大蔵省令
-    MinistryOfFinanceOrdinance,
-    /// 文部省令
-    MinistryOfEducationOrdinance,
-    /// 厚生省令
-    MinistryOfHealthAndWelfareOrdinance,
-    /// 農林省令
-    MinistryOfAgricultureAndForestryOrdinance,
-    /// 商工省令
-    MinistryOfCommerceAndIndustryOrdinance,
-    /// 運輸省令
-    MinistryOfTransportOrdinance,
-    /// 逓信省令
-    MinistryOfCommunicationsOrdinance,
-    /// 第一復員省令
-    FirstMinisterialOrdinanceForDemobilization,
-    /// 第二復員省令
-    SecondMinisterialOrdinanceForDemobilization,
-    /// 物価庁令
-    PriceAgencyOrdinance,
-    /// 中央労働委員会規則
-    CentralLaborRelationsCommissionRules,
-}
-
-impl MinistryContents for M3Ministry {
-    fn to_int(&self) -> usize {
-        use M3Ministry::*;
+    /// M1〜M6の区分の番号
+    pub fn period(&self) -> usize {
         match self {
-            CabinetOrder => 1,
-            ImperialHouseholdOrdinance => 2,
-            EconomicStabilityHeadquartersOrdinance => 3,
-            MinistryOfTheInteriorOrdinance => 4,
-            MinistryOfJusticeOrdinance => 5,
-            MinistryOfForeignAffairsOrdinance => 6,
-            MinistryOfFinanceOrdinance => 7,
-            MinistryOfEducationOrdinance => 8,
-            MinistryOfHealthAndWelfareOrdinance => 9,
-            MinistryOfAgricultureAndForestryOrdinance => 10,
-            MinistryOfCommerceAndIndustryOrdinance => 11,
-            MinistryOfTransportOrdinance => 12,
-            MinistryOfCommunicationsOrdinance => 13,
-            FirstMinisterialOrdinanceForDemobilization => 14,
-            SecondMinisterialOrdinanceForDemobilization => 15,
-            PriceAgencyOrdinance => 16,
-            CentralLaborRelationsCommissionRules => 21,
+            Self::M1(_) => 1,
+            Self::M2(_) => 2,
+            Self::M3(_) => 3,
+            Self::M4(_) => 4,
+            Self::M5(_) => 5,
+            Self::M6(_) => 6,
         }
     }
 
-    fn from_int(n: usize) -> Option<Self> {
-        use M3Ministry::*;
-        match n {
-            1 => Some(CabinetOrder),
-            2 => Some(ImperialHouseholdOrdinance),
-            3 => Some(EconomicStabilityHeadquartersOrdinance),
-            4 => Some(MinistryOfTheInteriorOrdinance),
-            5 => Some(MinistryOfJusticeOrdinance),
-            6 => Some(MinistryOfForeignAffairsOrdinance),
-            7 => Some(MinistryOfFinanceOrdinance),
-            8 => Some(MinistryOfEducationOrdinance),
-            9 => Some(MinistryOfHealthAndWelfareOrdinance),
-            10 => Some(MinistryOfAgricultureAndForestryOrdinance),
-            11 => Some(MinistryOfCommerceAndIndustryOrdinance),
-            12 => Some(MinistryOfTransportOrdinance),
-            13 => Some(MinistryOfCommunicationsOrdinance),
-            14 => Some(FirstMinisterialOrdinanceForDemobilization),
-            15 => Some(SecondMinisterialOrdinanceForDemobilization),
-            16 => Some(PriceAgencyOrdinance),
-            21 => Some(CentralLaborRelationsCommissionRules),
-            _ => None,
+    /// 担当する府省のビット列
+    fn bits(&self) -> u32 {
+        match self {
+            Self::M1(l) => l.bits(),
+            Self::M2(l) => l.bits(),
+            Self::M3(l) => l.bits(),
+            Self::M4(l) => l.bits(),
+            Self::M5(l) => l.bits(),
+            Self::M6(l) => l.bits(),
         }
     }
 
-    fn start() -> Date {
-        Date::new_ad(1945, 12, 1)
-    }
-
-    fn end() -> Date {
-        Date::new_ad(1947, 5, 2)
-    }
-
-    fn from_name(name: &str) -> Vec<Self> {
-        let mut v = Vec::new();
-        if name.contains("閣") {
-            v.push(Self::CabinetOrder)
-        }
-        if name.contains("宮内省") {
-            v.push(Self::ImperialHouseholdOrdinance)
-        }
-        if name.contains("経済安定本部") {
-            v.push(Self::EconomicStabilityHeadquartersOrdinance)
-        }
-        if name.contains("内務省") {
-            v.push(Self::MinistryOfTheInteriorOrdinance)
-        }
-        if name.contains("司法省") {
-            v.push(Self::MinistryOfJusticeOrdinance)
-        }
-        if name.contains("外務省") {
-            v.push(Self::MinistryOfForeignAffairsOrdinance)
-        }
-        if name.contains("大蔵省") {
-            v.push(Self::MinistryOfFinanceOrdinance)
-        }
-        if name.contains("文部省") {
-            v.push(Self::MinistryOfEducationOrdinance)
-        }
-        if name.contains("厚生省") {
-            v.push(Self::MinistryOfHealthAndWelfareOrdinance)
-        }
-        if name.contains("農林省") {
-            v.push(Self::MinistryOfAgricultureAndForestryOrdinance)
-        }
-        if name.contains("商工省") {
-            v.push(Self::MinistryOfCommerceAndIndustryOrdinance)
-        }
-        if name.contains("運輸省") {
-            v.push(Self::MinistryOfTransportOrdinance)
-        }
-        if name.contains("逓信省") {
-            v.push(Self::MinistryOfCommunicationsOrdinance)
-        }
-        if name.contains("第一復員省") {
-            v.push(Self::FirstMinisterialOrdinanceForDemobilization)
-        }
-        if name.contains("第二復員省") {
-            v.push(Self::SecondMinisterialOrdinanceForDemobilization)
-        }
-        if name.contains("物価庁") {
-            v.push(Self::PriceAgencyOrdinance)
-        }
-        if name.contains("中央労働委員会") {
-            v.push(Self::CentralLaborRelationsCommissionRules)
-        }
-        v
+    /// 担当する府省の数
+    pub fn len(&self) -> usize {
+        self.bits().count_ones() as usize
     }
-}
-
-/// M4時（1947年5月3日〜1949年5月31日）での府・省
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub enum M4Ministry {
-    /// 法務庁令
-    LegalAffairsAgencyOrdinance,
-    /// 総理庁令
-    PrimeMinistersOfficeOrdinance,
-    /// 経済安定本部令
-    EconomicStabilityHeadquartersOrdinance,
-    /// 内務省令
-    MinistryOfTheInteriorOrdinance,
-    /// 司法省令
-    MinistryOfJusticeOrdinance,
-    /// 外務省令
-    MinistryOfForeignAffairsOrdinance,
-    /// 大蔵省令
-    MinistryOfFinanceOrdinance,
-    /// 文部省令
-    MinistryOfEducationOrdinance,
-    /// 厚生省令
-    MinistryOfHealthAndWelfareOrdinance,
-    /// 農林省令
-    MinistryOfAgricultureAndForestryOrdinance,
-    /// 通商産業省令
-    MinistryOfInternationalTradeAndIndustryOrdinance,
-    /// 運輸省令
-    MinistryOfTransportOrdinance,
-    /// 逓信省令
-    MinistryOfCommunicationsOrdinance,
-    /// 労働省令
-    MinistryOfLaborOrdinance,
-    /// 建設省令
-    MinistryOfConstructionOrdinance,
-    /// 物価庁令
-    PriceAgencyOrdinance,
-    /// 商工省令
-    MinistryOfCommerceAndIndustryOrdinance,
-    /// 中央労働委員会規則
-    CentralLaborRelationsCommissionRules,
-    /// 公正取引委員会規則
-    FairTradeCommissionRules,
-    /// 国家公安委員会規則
-    NationalPublicSafetyCommissionRegulations,
-}
 
-impl MinistryContents for M4Ministry {
-    fn to_int(&self) -> usize {
-        use M4Ministry::*;
+    /// 担当する府省が一つも含まれていないかどうか
+    pub fn is_empty(&self) -> bool {
         match self {
-            LegalAffairsAgencyOrdinance => 1,
-            PrimeMinistersOfficeOrdinance => 2,
-            EconomicStabilityHeadquartersOrdinance => 3,
-            MinistryOfTheInteriorOrdinance => 4,
-            MinistryOfJusticeOrdinance => 5,
-            MinistryOfForeignAffairsOrdinance => 6,
-            MinistryOfFinanceOrdinance => 7,
-            MinistryOfEducationOrdinance => 8,
-            MinistryOfHealthAndWelfareOrdinance => 9,
-            MinistryOfAgricultureAndForestryOrdinance => 10,
-            MinistryOfInternationalTradeAndIndustryOrdinance => 11,
-            MinistryOfTransportOrdinance => 12,
-            MinistryOfCommunicationsOrdinance => 13,
-            MinistryOfLaborOrdinance => 14,
-            MinistryOfConstructionOrdinance => 15,
-            PriceAgencyOrdinance => 16,
-            MinistryOfCommerceAndIndustryOrdinance => 17,
-            CentralLaborRelationsCommissionRules => 21,
-            FairTradeCommissionRules => 22,
-            NationalPublicSafetyCommissionRegulations => 23,
-        }
-    }
-
-    fn from_int(n: usize) -> Option<Self> {
-        use M4Ministry::*;
-        match n {
-            1 => Some(LegalAffairsAgencyOrdinance),
-            2 => Some(PrimeMinistersOfficeOrdinance),
-            3 => Some(EconomicStabilityHeadquartersOrdinance),
-            4 => Some(MinistryOfTheInteriorOrdinance),
-            5 => Some(MinistryOfJusticeOrdinance),
-            6 => Some(MinistryOfForeignAffairsOrdinance),
-            7 => Some(MinistryOfFinanceOrdinance),
-            8 => Some(MinistryOfEducationOrdinance),
-            9 => Some(MinistryOfHealthAndWelfareOrdinance),
-            10 => Some(MinistryOfAgricultureAndForestryOrdinance),
-            11 => Some(MinistryOfInternationalTradeAndIndustryOrdinance),
-            12 => Some(MinistryOfTransportOrdinance),
-            13 => Some(MinistryOfCommunicationsOrdinance),
-            14 => Some(MinistryOfLaborOrdinance),
-            15 => Some(MinistryOfConstructionOrdinance),
-            16 => Some(PriceAgencyOrdinance),
-            17 => Some(MinistryOfCommerceAndIndustryOrdinance),
-            21 => Some(CentralLaborRelationsCommissionRules),
-            22 => Some(FairTradeCommissionRules),
-            23 => Some(NationalPublicSafetyCommissionRegulations),
-            _ => None,
+            Self::M1(l) => l.is_empty(),
+            Self::M2(l) => l.is_empty(),
+            Self::M3(l) => l.is_empty(),
+            Self::M4(l) => l.is_empty(),
+            Self::M5(l) => l.is_empty(),
+            Self::M6(l) => l.is_empty(),
         }
     }
+}
 
-    fn start() -> Date {
-        Date::new_ad(1947, 5, 3)
-    }
-
-    fn end() -> Date {
-        Date::new_ad(1949, 5, 31)
+impl fmt::Display for Ministry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_id_str())
     }
+}
 
-    fn from_name(name: &str) -> Vec<Self> {
-        let mut v = Vec::new();
-        if name.contains("法務庁") {
-            v.push(Self::LegalAffairsAgencyOrdinance)
-        }
-        if name.contains("総理庁") {
-            v.push(Self::PrimeMinistersOfficeOrdinance)
-        }
-        if name.contains("経済安定本部") {
-            v.push(Self::EconomicStabilityHeadquartersOrdinance)
-        }
-        if name.contains("内務省") {
-            v.push(Self::MinistryOfTheInteriorOrdinance)
-        }
-        if name.contains("司法省") {
-            v.push(Self::MinistryOfJusticeOrdinance)
-        }
-        if name.contains("外務省") {
-            v.push(Self::MinistryOfForeignAffairsOrdinance)
-        }
-        if name.contains("大蔵省") {
-            v.push(Self::MinistryOfFinanceOrdinance)
-        }
-        if name.contains("文部省") {
-            v.push(Self::MinistryOfEducationOrdinance)
-        }
-        if name.contains("厚生省") {
-            v.push(Self::MinistryOfHealthAndWelfareOrdinance)
-        }
-        if name.contains("農林省") {
-            v.push(Self::MinistryOfAgricultureAndForestryOrdinance)
-        }
-        if name.contains("通商産業省") {
-            v.push(Self::MinistryOfInternationalTradeAndIndustryOrdinance)
-        }
-        if name.contains("運輸省") {
-            v.push(Self::MinistryOfTransportOrdinance)
-        }
-        if name.contains("逓信省") {
-            v.push(Self::MinistryOfCommunicationsOrdinance)
-        }
-        if name.contains("労働省") {
-            v.push(Self::MinistryOfLaborOrdinance)
-        }
-        if name.contains("建設省") {
-            v.push(Self::MinistryOfConstructionOrdinance)
-        }
-        if name.contains("物価庁") {
-            v.push(Self::PriceAgencyOrdinance)
-        }
-        if name.contains("商工省") {
-            v.push(Self::MinistryOfCommerceAndIndustryOrdinance)
-        }
-        if name.contains("中央労働委員会") {
-            v.push(Self::CentralLaborRelationsCommissionRules)
-        }
-        if name.contains("公正取引委員会") {
-            v.push(Self::FairTradeCommissionRules)
-        }
-        if name.contains("国家公安委員会") {
-            v.push(Self::NationalPublicSafetyCommissionRegulations)
-        }
-        v
+impl FromStr for Ministry {
+    type Err = LawIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_id_str(s)
     }
 }
 
-/// M5時（1949年6月1日〜2001年1月15日）での府・省
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub enum M5Ministry {
-    /// 法務庁令
-    LegalAffairsAgencyOrdinance,
-    /// 総理庁令
-    PrimeMinistersOfficeOrdinance,
-    /// 経済安定本部令
-    EconomicStabilityHeadquartersOrdinance,
-    /// 自治省令
-    MinistryOfHomeAffairsOrdinance,
-    /// 法務省令
-    MinistryOfJusticeOrdinance,
-    /// 外務省令
-    MinistryOfForeignAffairsOrdinance,
-    /// 大蔵省令
-    MinistryOfFinanceOrdinance,
-    /// 文部省令
-    MinistryOfEducationOrdinance,
-    /// 厚生省令
-    MinistryOfHealthAndWelfareOrdinance,
-    /// 農林水産省令
-    MinistryOfAgricultureAndForestryAndFisheriesOrdinance,
-    /// 通商産業省令
-    MinistryOfInternationalTradeAndIndustryOrdinance,
-    /// 運輸省令
-    MinistryOfTransportOrdinance,
-    /// 郵政省令
-    MinistryOfPostsAndTelecommunicationsOrdinance,
-    /// 労働省令
-    MinistryOfLaborOrdinance,
-    /// 建設省令
-    MinistryOfConstructionOrdinance,
-    /// 物価庁令
-    PriceAgencyOrdinance,
-    /// 農林省令
-    MinistryOfAgricultureAndForestryOrdinance,
-    /// 電気通信省令
-    TelecommunicationsMinisterialOrdinance,
-    /// 中央省庁等改革推進本部令
-    CentralMinistriesAndAgenciesReformPromotionHeadquartersOrdinance,
-    /// 電波監理委員会規則
-    RadioRegulatoryCommissionRules,
-    /// 中央労働委員会規則
-    CentralLaborRelationsCommissionRules,
-    /// 公正取引委員会規則
-    FairTradeCommissionRules,
-    /// 国家公安委員会規則
-    NationalPublicSafetyCommissionRegulations,
-    /// 公害等調整委員会規則
-    PollutionAdjustmentCommitteeRules,
-    /// 公安審査委員会規則
-    PublicSafetyReviewCommitteeRules,
-}
-
-impl MinistryContents for M5Ministry {
-    fn to_int(&self) -> usize {
-        use M5Ministry::*;
-        match self {
-            LegalAffairsAgencyOrdinance => 1,
-            PrimeMinistersOfficeOrdinance => 2,
-            EconomicStabilityHeadquartersOrdinance => 3,
-            MinistryOfHomeAffairsOrdinance => 4,
-            MinistryOfJusticeOrdinance => 5,
-            MinistryOfForeignAffairsOrdinance => 6,
-            MinistryOfFinanceOrdinance => 7,
-            MinistryOfEducationOrdinance => 8,
-            MinistryOfHealthAndWelfareOrdinance => 9,
-            MinistryOfAgricultureAndForestryAndFisheriesOrdinance => 10,
-            MinistryOfInternationalTradeAndIndustryOrdinance => 11,
-            MinistryOfTransportOrdinance => 12,
-            MinistryOfPostsAndTelecommunicationsOrdinance => 13,
-            MinistryOfLaborOrdinance => 14,
-            MinistryOfConstructionOrdinance => 15,
-            PriceAgencyOrdinance => 16,
-            MinistryOfAgricultureAndForestryOrdinance => 17,
-            TelecommunicationsMinisterialOrdinance => 18,
-            CentralMinistriesAndAgenciesReformPromotionHeadquartersOrdinance => 19,
-            RadioRegulatoryCommissionRules => 20,
-            CentralLaborRelationsCommissionRules => 21,
-            FairTradeCommissionRules => 22,
-            NationalPublicSafetyCommissionRegulations => 23,
-            PollutionAdjustmentCommitteeRules => 24,
-            PublicSafetyReviewCommitteeRules => 25,
-        }
-    }
-
-    fn from_int(n: usize) -> Option<Self> {
-        use M5Ministry::*;
-        match n {
-            1 => Some(LegalAffairsAgencyOrdinance),
-            2 => Some(PrimeMinistersOfficeOrdinance),
-            3 => Some(EconomicStabilityHeadquartersOrdinance),
-            4 => Some(MinistryOfHomeAffairsOrdinance),
-            5 => Some(MinistryOfJusticeOrdinance),
-            6 => Some(MinistryOfForeignAffairsOrdinance),
-            7 => Some(MinistryOfFinanceOrdinance),
-            8 => Some(MinistryOfEducationOrdinance),
-            9 => Some(MinistryOfHealthAndWelfareOrdinance),
-            10 => Some(MinistryOfAgricultureAndForestryAndFisheriesOrdinance),
-            11 => Some(MinistryOfInternationalTradeAndIndustryOrdinance),
-            12 => Some(MinistryOfTransportOrdinance),
-            13 => Some(MinistryOfPostsAndTelecommunicationsOrdinance),
-            14 => Some(MinistryOfLaborOrdinance),
-            15 => Some(MinistryOfConstructionOrdinance),
-            16 => Some(PriceAgencyOrdinance),
-            17 => Some(MinistryOfAgricultureAndForestryOrdinance),
-            18 => Some(TelecommunicationsMinisterialOrdinance),
-            19 => Some(CentralMinistriesAndAgenciesReformPromotionHeadquartersOrdinance),
-            20 => Some(RadioRegulatoryCommissionRules),
-            21 => Some(CentralLaborRelationsCommissionRules),
-            22 => Some(FairTradeCommissionRules),
-            23 => Some(NationalPublicSafetyCommissionRegulations),
-            24 => Some(PollutionAdjustmentCommitteeRules),
-            25 => Some(PublicSafetyReviewCommitteeRules),
-            _ => None,
-        }
-    }
-
-    fn start() -> Date {
-        Date::new_ad(1949, 6, 1)
-    }
-
-    fn end() -> Date {
-        Date::new_ad(2001, 1, 5)
-    }
-
-    fn from_name(name: &str) -> Vec<Self> {
-        let mut v = Vec::new();
-        if name.contains("法務庁") {
-            v.push(Self::LegalAffairsAgencyOrdinance)
-        }
-        if name.contains("総理庁") {
-            v.push(Self::PrimeMinistersOfficeOrdinance)
-        }
-        if name.contains("経済安定本部") {
-            v.push(Self::EconomicStabilityHeadquartersOrdinance)
-        }
-        if name.contains("自治省") {
-            v.push(Self::MinistryOfHomeAffairsOrdinance)
-        }
-        if name.contains("法務省") {
-            v.push(Self::MinistryOfJusticeOrdinance)
-        }
-        if name.contains("外務省") {
-            v.push(Self::MinistryOfForeignAffairsOrdinance)
-        }
-        if name.contains("大蔵省") {
-            v.push(Self::MinistryOfFinanceOrdinance)
-        }
-        if name.contains("文部省") {
-            v.push(Self::MinistryOfEducationOrdinance)
-        }
-        if name.contains("厚生省") {
-            v.push(Self::MinistryOfHealthAndWelfareOrdinance)
-        }
-        if name.contains("農林水産省") {
-            v.push(Self::MinistryOfAgricultureAndForestryAndFisheriesOrdinance)
-        }
-        if name.contains("通商産業省") {
-            v.push(Self::MinistryOfInternationalTradeAndIndustryOrdinance)
-        }
-        if name.contains("運輸省") {
-            v.push(Self::MinistryOfTransportOrdinance)
-        }
-        if name.contains("郵政省") {
-            v.push(Self::MinistryOfPostsAndTelecommunicationsOrdinance)
-        }
-        if name.contains("労働省") {
-            v.push(Self::MinistryOfLaborOrdinance)
-        }
-        if name.contains("建設省") {
-            v.push(Self::MinistryOfConstructionOrdinance)
-        }
-        if name.contains("物価庁") {
-            v.push(Self::PriceAgencyOrdinance)
-        }
-        if name.contains("農林省") {
-            v.push(Self::MinistryOfAgricultureAndForestryOrdinance)
-        }
-        if name.contains("電気通信省") {
-            v.push(Self::TelecommunicationsMinisterialOrdinance)
-        }
-        if name.contains("中央省庁等改革推進本部") {
-            v.push(Self::CentralMinistriesAndAgenciesReformPromotionHeadquartersOrdinance)
-        }
-        if name.contains("電波監理委員会") {
-            v.push(Self::RadioRegulatoryCommissionRules)
-        }
-        if name.contains("中央労働委員会") {
-            v.push(Self::CentralLaborRelationsCommissionRules)
-        }
-        if name.contains("公正取引委員会") {
-            v.push(Self::FairTradeCommissionRules)
-        }
-        if name.contains("国家公安委員会") {
-            v.push(Self::NationalPublicSafetyCommissionRegulations)
-        }
-        if name.contains("公害等調整委員会") {
-            v.push(Self::PollutionAdjustmentCommitteeRules)
-        }
-        if name.contains("公安審査委員会") {
-            v.push(Self::PublicSafetyReviewCommitteeRules)
-        }
-        v
-    }
-}
-
-/// M6時（2001年1月16日〜）での府・省
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub enum M6Ministry {
-    /// 内閣官房令
-    CabinetSecretariatOrdinance,
-    /// 総理庁令
-    PrimeMinistersOfficeOrdinance,
-    /// 復興庁令
-    ReconstructionAgencyOrdinance,
-    /// 自治省令
-    MinistryOfHomeAffairsOrdinance,
-    /// 法務省令
-    MinistryOfJusticeOrdinance,
-    /// 外務省令
-    MinistryOfForeignAffairsOrdinance,
-    /// 財務省令
-    MinistryOfFinanceOrdinance,
-    /// 文部科学省令
-    MinistryOfEducationAndCultureAndSportsAndScienceAndTechnologyOrdinance,
-    /// 厚生労働省令
-    MinistryOfHealthAndLaborAndWelfareOrdinance,
-    /// 農林水産省令
-    MinistryOfAgricultureAndForestryAndFisheriesOrdinance,
-    /// 経済産業省令
-    MinistryOfEconomyAndTradeAndIndustryOrdinance,
-    /// 国土交通省令
-    MinistryOfLandAndInfrastructureAndTransportAndTourismOrdinance,
-    /// 環境省令
-    MinistryOfTheEnvironmentOrdinance,
-    /// 防衛省令
-    MinistryOfDefenseOrdinance,
-    /// デジタル庁令
-    DigitalAgencyOrdinance,
-    /// 特定個人情報保護委員会規則
-    SpecificPersonalInformationProtectionCommissionRules,
-    /// 運輸安全委員会規則
-    JapanTransportSafetyBoardRegulations,
-    /// 原子力規制委員会規則
-    NuclearRegulationAuthorityRegulations,
-    /// 中央労働委員会規則
-    CentralLaborRelationsCommissionRules,
-    /// 公正取引委員会規則
-    FairTradeCommissionRules,
-    /// 国家公安委員会規則
-    NationalPublicSafetyCommissionRegulations,
-    /// 公害等調整委員会規則
-    PollutionAdjustmentCommitteeRules,
-    /// 公安審査委員会規則
-    PublicSafetyReviewCommitteeRules,
-    /// カジノ管理委員会規則
-    CasinoManagementCommitteeRules,
-}
-
-impl MinistryContents for M6Ministry {
-    fn to_int(&self) -> usize {
-        use M6Ministry::*;
-        match self {
-            CabinetSecretariatOrdinance => 1,
-            PrimeMinistersOfficeOrdinance => 2,
-            ReconstructionAgencyOrdinance => 3,
-            MinistryOfHomeAffairsOrdinance => 4,
-            MinistryOfJusticeOrdinance => 5,
-            MinistryOfForeignAffairsOrdinance => 6,
-            MinistryOfFinanceOrdinance => 7,
-            MinistryOfEducationAndCultureAndSportsAndScienceAndTechnologyOrdinance => 8,
-            MinistryOfHealthAndLaborAndWelfareOrdinance => 9,
-            MinistryOfAgricultureAndForestryAndFisheriesOrdinance => 10,
-            MinistryOfEconomyAndTradeAndIndustryOrdinance => 11,
-            MinistryOfLandAndInfrastructureAndTransportAndTourismOrdinance => 12,
-            MinistryOfTheEnvironmentOrdinance => 13,
-            MinistryOfDefenseOrdinance => 14,
-            DigitalAgencyOrdinance => 15,
-            SpecificPersonalInformationProtectionCommissionRules => 18,
-            JapanTransportSafetyBoardRegulations => 19,
-            NuclearRegulationAuthorityRegulations => 20,
-            CentralLaborRelationsCommissionRules => 21,
-            FairTradeCommissionRules => 22,
-            NationalPublicSafetyCommissionRegulations => 23,
-            PollutionAdjustmentCommitteeRules => 24,
-            PublicSafetyReviewCommitteeRules => 25,
-            CasinoManagementCommitteeRules => 26,
-        }
-    }
-
-    fn from_int(n: usize) -> Option<Self> {
-        use M6Ministry::*;
-        match n {
-            1 => Some(CabinetSecretariatOrdinance),
-            2 => Some(PrimeMinistersOfficeOrdinance),
-            3 => Some(ReconstructionAgencyOrdinance),
-            4 => Some(MinistryOfHomeAffairsOrdinance),
-            5 => Some(MinistryOfJusticeOrdinance),
-            6 => Some(MinistryOfForeignAffairsOrdinance),
-            7 => Some(MinistryOfFinanceOrdinance),
-            8 => Some(MinistryOfEducationAndCultureAndSportsAndScienceAndTechnologyOrdinance),
-            9 => Some(MinistryOfHealthAndLaborAndWelfareOrdinance),
-            10 => Some(MinistryOfAgricultureAndForestryAndFisheriesOrdinance),
-            11 => Some(MinistryOfEconomyAndTradeAndIndustryOrdinance),
-            12 => Some(MinistryOfLandAndInfrastructureAndTransportAndTourismOrdinance),
-            13 => Some(MinistryOfTheEnvironmentOrdinance),
-            14 => Some(MinistryOfDefenseOrdinance),
-            15 => Some(DigitalAgencyOrdinance),
-            18 => Some(SpecificPersonalInformationProtectionCommissionRules),
-            19 => Some(JapanTransportSafetyBoardRegulations),
-            20 => Some(NuclearRegulationAuthorityRegulations),
-            21 => Some(CentralLaborRelationsCommissionRules),
-            22 => Some(FairTradeCommissionRules),
-            23 => Some(NationalPublicSafetyCommissionRegulations),
-            24 => Some(PollutionAdjustmentCommitteeRules),
-            25 => Some(PublicSafetyReviewCommitteeRules),
-            26 => Some(CasinoManagementCommitteeRules),
-            _ => None,
-        }
-    }
-
-    fn start() -> Date {
-        Date::new_ad(2001, 1, 6)
-    }
-
-    /// 施行中なのでMAX
-    fn end() -> Date {
-        Date::new_ad(usize::MAX, 12, 31)
-    }
-
-    fn from_name(name: &str) -> Vec<Self> {
-        let mut v = Vec::new();
-        if name.contains("内閣官房") {
-            v.push(Self::CabinetSecretariatOrdinance)
-        }
-        if name.contains("総理庁") {
-            v.push(Self::PrimeMinistersOfficeOrdinance)
-        }
-        if name.contains("復興庁") {
-            v.push(Self::MinistryOfHomeAffairsOrdinance)
-        }
-        if name.contains("自治省") {
-            v.push(Self::MinistryOfHomeAffairsOrdinance)
-        }
-        if name.contains("法務省") {
-            v.push(Self::MinistryOfJusticeOrdinance)
-        }
-        if name.contains("外務省") {
-            v.push(Self::MinistryOfForeignAffairsOrdinance)
-        }
-        if name.contains("財務省") {
-            v.push(Self::MinistryOfFinanceOrdinance)
-        }
-        if name.contains("文部科学省") {
-            v.push(Self::MinistryOfEducationAndCultureAndSportsAndScienceAndTechnologyOrdinance)
-        }
-        if name.contains("厚生労働省") {
-            v.push(Self::MinistryOfHealthAndLaborAndWelfareOrdinance)
-        }
-        if name.contains("農林水産省") {
-            v.push(Self::MinistryOfAgricultureAndForestryAndFisheriesOrdinance)
-        }
-        if name.contains("経済産業省") {
-            v.push(Self::MinistryOfEconomyAndTradeAndIndustryOrdinance)
-        }
-        if name.contains("国土交通省") {
-            v.push(Self::MinistryOfLandAndInfrastructureAndTransportAndTourismOrdinance)
-        }
-        if name.contains("環境省") {
-            v.push(Self::MinistryOfTheEnvironmentOrdinance)
-        }
-        if name.contains("防衛省") {
-            v.push(Self::MinistryOfDefenseOrdinance)
-        }
-        if name.contains("デジタル庁") {
-            v.push(Self::DigitalAgencyOrdinance)
-        }
-        if name.contains("特定個人情報保護委員会") {
-            v.push(Self::SpecificPersonalInformationProtectionCommissionRules)
-        }
-        if name.contains("運輸安全委員会") {
-            v.push(Self::JapanTransportSafetyBoardRegulations)
-        }
-        if name.contains("原子力規制委員会") {
-            v.push(Self::NuclearRegulationAuthorityRegulations)
-        }
-        if name.contains("中央労働委員会") {
-            v.push(Self::CentralLaborRelationsCommissionRules)
-        }
-        if name.contains("公正取引委員会") {
-            v.push(Self::FairTradeCommissionRules)
-        }
-        if name.contains("国家公安委員会") {
-            v.push(Self::NationalPublicSafetyCommissionRegulations)
-        }
-        if name.contains("公害等調整委員会") {
-            v.push(Self::PollutionAdjustmentCommitteeRules)
-        }
-        if name.contains("公安審査委員会") {
-            v.push(Self::PublicSafetyReviewCommitteeRules)
-        }
-        if name.contains("カジノ管理委員会") {
-            v.push(Self::CasinoManagementCommitteeRules)
-        }
-        v
-    }
-}
-
-/// 府・省
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
-pub enum Ministry {
-    /// 1869年7月8日〜1943年10月31日
-    M1(Vec<M1Ministry>),
-    /// 1943年11月1日〜1945年11月31日
-    M2(Vec<M2Ministry>),
-    /// 1945年12月1日〜1947年5月2日
-    M3(Vec<M3Ministry>),
-    /// 1947年5月3日〜1949年5月31日
-    M4(Vec<M4Ministry>),
-    /// 1949年6月1日〜2001年1月15日
-    M5(Vec<M5Ministry>),
-    /// 2001年1月16日〜
-    M6(Vec<M6Ministry>),
-}
-
-impl Ministry {
-    pub fn to_id_str(&self) -> String {
-        match self {
-            Self::M1(l) => format!("M1{}", M1Ministry::to_id_str(l)),
-            Self::M2(l) => format!("M2{}", M2Ministry::to_id_str(l)),
-            Self::M3(l) => format!("M3{}", M3Ministry::to_id_str(l)),
-            Self::M4(l) => format!("M4{}", M4Ministry::to_id_str(l)),
-            Self::M5(l) => format!("M5{}", M5Ministry::to_id_str(l)),
-            Self::M6(l) => format!("M6{}", M6Ministry::to_id_str(l)),
-        }
-    }
-
-    pub fn from_id_str(s: &str) -> Result<Self, String> {
-        if &s[0..=0] == "M" {
-            let ministry = match &s[1..=1] {
-                "1" => {
-                    let n = usize::from_str_radix(&s[2..=8], 16)
-                        .map_err(|_| String::from("unexpected string"))?;
-                    let byte_s = format!("{n:028b}");
-                    let l = M1Ministry::from_id_str(&byte_s)?;
-                    Ministry::M1(l)
-                }
-                "2" => {
-                    let n = usize::from_str_radix(&s[2..=8], 16)
-                        .map_err(|_| String::from("unexpected string"))?;
-                    let byte_s = format!("{n:028b}");
-                    let l = M2Ministry::from_id_str(&byte_s)?;
-                    Ministry::M2(l)
-                }
-                "3" => {
-                    let n = usize::from_str_radix(&s[2..=8], 16)
-                        .map_err(|_| String::from("unexpected string"))?;
-                    let byte_s = format!("{n:028b}");
-                    let l = M3Ministry::from_id_str(&byte_s)?;
-                    Ministry::M3(l)
-                }
-                "4" => {
-                    let n = usize::from_str_radix(&s[2..=8], 16)
-                        .map_err(|_| String::from("unexpected string"))?;
-                    let byte_s = format!("{n:028b}");
-                    let l = M4Ministry::from_id_str(&byte_s)?;
-                    Ministry::M4(l)
-                }
-                "5" => {
-                    let n = usize::from_str_radix(&s[2..=8], 16)
-                        .map_err(|_| String::from("unexpected string"))?;
-                    let byte_s = format!("{n:028b}");
-                    let l = M5Ministry::from_id_str(&byte_s)?;
-                    Ministry::M5(l)
-                }
-                "6" => {
-                    let n = usize::from_str_radix(&s[2..=8], 16)
-                        .map_err(|_| String::from("unexpected string"))?;
-                    let byte_s = format!("{n:028b}");
-                    let l = M6Ministry::from_id_str(&byte_s)?;
-                    Ministry::M6(l)
-                }
-                _ => return Err(String::from("unexpected string")),
-            };
-            Ok(ministry)
-        } else {
-            Err(String::from("unexpected string"))
-        }
-    }
-
-    pub fn from_name(name: &str) -> Result<Self, String> {
-        let err_msg = String::from("Unexpected input");
-        let re = Regex::new(r"(?<wareki>(明治|大正|昭和|平成|令和)[一|二|三|四|五|六|七|八|九|十|百|1|2|3|4|5|6|7|8|9|0|１|２|３|４|５|６|７|８|９|０]+)年([一|二|三|四|五|六|七|八|九|十|百|1|2|3|4|5|6|7|8|9|0|１|２|３|４|５|６|７|８|９|０]+月)?([一|二|三|四|五|六|七|八|九|十|百|1|2|3|4|5|6|7|8|9|0|１|２|３|４|５|６|７|８|９|０]+日)?(?<ministry>.+)(令|規則)").unwrap();
-        if let Some(caps) = re.captures(name) {
-            let ministry_s = &caps["ministry"];
-            let wareki_s = &caps["wareki"];
-            let wareki = Wareki::from_text(wareki_s).ok_or(err_msg.clone())?;
-            if M1Ministry::applicable_wareki(wareki) {
-                let l = M1Ministry::from_name(ministry_s);
-                Ok(Ministry::M1(l))
-            } else if M2Ministry::applicable_wareki(wareki) {
-                let l = M2Ministry::from_name(ministry_s);
-                Ok(Ministry::M2(l))
-            } else if M3Ministry::applicable_wareki(wareki) {
-                let l = M3Ministry::from_name(ministry_s);
-                Ok(Ministry::M3(l))
-            } else if M4Ministry::applicable_wareki(wareki) {
-                let l = M4Ministry::from_name(ministry_s);
-                Ok(Ministry::M4(l))
-            } else if M5Ministry::applicable_wareki(wareki) {
-                let l = M5Ministry::from_name(ministry_s);
-                Ok(Ministry::M5(l))
-            } else if M6Ministry::applicable_wareki(wareki) {
-                let l = M6Ministry::from_name(ministry_s);
-                Ok(Ministry::M6(l))
-            } else {
-                Err(err_msg.clone())
-            }
-        } else {
-            Err(err_msg)
-        }
-    }
-}
-
-/// 機関名
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub enum Institution {
-    /// 会計検査院
-    BoardOfAudit,
-    /// 海上保安庁
-    CoastGuard,
-    /// 日本学術会議
-    ScienceCouncilOfJapan,
-    /// 土地調整委員会
-    LandAdjustmentCommittee,
-    /// 金融再生委員会
-    FinancialReconstructionCommittee,
-    /// 首都圏整備委員会
-    MetropolitanAreaDevelopmentCommittee,
-    /// 地方財政委員会
-    LocalFinanceCommittee,
-    /// 司法試験管理委員会
-    BarExaminationManagementCommittee,
-    /// 公認会計士管理委員会
-    CertifiedPublicAccountantManagementCommittee,
-    /// 外資委員会
-    ForeignInvestmentCommittee,
-    /// 文化財保護委員会
-    CulturalPropertiesProtectionCommittee,
-    /// 日本ユネスコ国内委員会
-    JapaneseNationalCommissionForUNESCO,
-    /// 最高裁判所
-    SupremeCourt,
-    /// 衆議院
-    HouseOfRepresentatives,
-    /// 参議院
-    HouseOfCouncilors,
-    /// 船員中央労働委員会
-    SeafarersCentralLaborCommittee,
-    /// 司法試験管理委員会
-    /// 電波監理委員会
-    RadioRegulatoryCommission,
-    /// カジノ管理委員会
-    CasinoManagementCommittee,
-}
-
-impl Institution {
-    pub fn to_int(&self) -> usize {
-        use Institution::*;
-        match self {
-            BoardOfAudit => 1,
-            CoastGuard => 2,
-            ScienceCouncilOfJapan => 3,
-            LandAdjustmentCommittee => 4,
-            FinancialReconstructionCommittee => 5,
-            MetropolitanAreaDevelopmentCommittee => 6,
-            LocalFinanceCommittee => 7,
-            BarExaminationManagementCommittee => 8,
-            CertifiedPublicAccountantManagementCommittee => 9,
-            ForeignInvestmentCommittee => 10,
-            CulturalPropertiesProtectionCommittee => 11,
-            JapaneseNationalCommissionForUNESCO => 12,
-            SupremeCourt => 13,
-            HouseOfRepresentatives => 14,
-            HouseOfCouncilors => 15,
-            SeafarersCentralLaborCommittee => 16,
-            RadioRegulatoryCommission => 18,
-            CasinoManagementCommittee => 19,
-        }
-    }
-
-    pub fn from_int(n: usize) -> Option<Self> {
-        use Institution::*;
-        match n {
-            1 => Some(BoardOfAudit),
-            2 => Some(CoastGuard),
-            3 => Some(ScienceCouncilOfJapan),
-            4 => Some(LandAdjustmentCommittee),
-            5 => Some(FinancialReconstructionCommittee),
-            6 => Some(MetropolitanAreaDevelopmentCommittee),
-            7 => Some(LocalFinanceCommittee),
-            8 => Some(BarExaminationManagementCommittee),
-            9 => Some(CertifiedPublicAccountantManagementCommittee),
-            10 => Some(ForeignInvestmentCommittee),
-            11 => Some(CulturalPropertiesProtectionCommittee),
-            12 => Some(JapaneseNationalCommissionForUNESCO),
-            13 => Some(SupremeCourt),
-            14 => Some(HouseOfRepresentatives),
-            15 => Some(HouseOfCouncilors),
-            16 => Some(SeafarersCentralLaborCommittee),
-            17 => Some(BarExaminationManagementCommittee),
-            18 => Some(RadioRegulatoryCommission),
-            19 => Some(CasinoManagementCommittee),
-            _ => None,
-        }
-    }
-
-    /// 「会計検査院規則」などから導き出す
-    pub fn from_name(name: &str) -> Option<Self> {
-        if name.contains("会計検査院") {
-            Some(Self::BoardOfAudit)
-        } else if name.contains("海上保安庁") {
-            Some(Self::CoastGuard)
-        } else if name.contains("日本学術会議") {
-            Some(Self::ScienceCouncilOfJapan)
-        } else if name.contains("土地調整委員会") {
-            Some(Self::LandAdjustmentCommittee)
-        } else if name.contains("金融再生委員会") {
-            Some(Self::FinancialReconstructionCommittee)
-        } else if name.contains("首都圏整備委員会") {
-            Some(Self::MetropolitanAreaDevelopmentCommittee)
-        } else if name.contains("地方財政委員会") {
-            Some(Self::LocalFinanceCommittee)
-        } else if name.contains("司法試験管理委員会") {
-            Some(Self::BarExaminationManagementCommittee)
-        } else if name.contains("公認会計士管理委員会") {
-            Some(Self::CertifiedPublicAccountantManagementCommittee)
-        } else if name.contains("外資委員会") {
-            Some(Self::ForeignInvestmentCommittee)
-        } else if name.contains("文化財保護委員会") {
-            Some(Self::CulturalPropertiesProtectionCommittee)
-        } else if name.contains("日本ユネスコ国内委員会") {
-            Some(Self::JapaneseNationalCommissionForUNESCO)
-        } else if name.contains("最高裁判所") {
-            Some(Self::SupremeCourt)
-        } else if name.contains("衆議院") {
-            Some(Self::HouseOfRepresentatives)
-        } else if name.contains("参議院") {
-            Some(Self::HouseOfCouncilors)
-        } else if name.contains("船員中央労働委員会") {
-            Some(Self::SeafarersCentralLaborCommittee)
-        } else if name.contains("電波監理委員会") {
-            Some(Self::RadioRegulatoryCommission)
-        } else if name.contains("カジノ管理委員会") {
-            Some(Self::CasinoManagementCommittee)
-        } else {
-            None
-        }
-    }
-}
+impl_str_conversions!(Ministry);
 
 /// 法令IDの詳細 <https://elaws.e-gov.go.jp/file/LawIdNamingConvention.pdf> を参照
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(remote = "Self")
+)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum LawType {
     /// 憲法
     Constitution,
@@ -1741,7 +850,11 @@ pub enum LawType {
     /// 太政官布達
     DajokanHutatsu { efficacy: LawEfficacy, num: usize },
     /// 府省令
-    MinistryOrder { ministry: Ministry, num: usize },
+    MinistryOrder {
+        #[cfg_attr(feature = "serde", serde(with = "Ministry"))]
+        ministry: Ministry,
+        num: usize,
+    },
     /// 人事院規則
     Jinjin {
         /// 規則の分類
@@ -1808,112 +921,301 @@ impl LawType {
         }
     }
 
-    pub fn from_id_str(s: &str) -> Option<Self> {
+    /// 法令IDのうち，元号と年を除いた12文字から生成
+    pub fn from_id_str(s: &str) -> Result<Self, LawIdError> {
         use LawType::*;
+        check_id_chars(s, 12)?;
         if s == "CONSTITUTION" {
-            Some(Constitution)
-        } else if &s[0..=1] == "AC" {
-            let rippou_type_s = &s[2..=8].parse::<usize>().ok()?;
-            let rippou_type = if *rippou_type_s == 0 {
-                RippouType::Kakuhou
-            } else if *rippou_type_s == 1000000 {
-                RippouType::Syuin
-            } else if *rippou_type_s == 100000 {
-                RippouType::Sanin
-            } else {
-                return None;
-            };
-            let num = s[9..=11].parse::<usize>().ok()?;
-            Some(Act { rippou_type, num })
-        } else if &s[0..=1] == "CO" {
-            let efficacy_s = &s[2..=8].parse::<usize>().ok()?;
-            let efficacy = if *efficacy_s == 0 {
-                LawEfficacy::CabinetOrder
-            } else if *efficacy_s == 1000000 {
-                LawEfficacy::Law
-            } else {
-                return None;
-            };
-            let num = s[9..=11].parse::<usize>().ok()?;
-            Some(CabinetOrder { efficacy, num })
-        } else if &s[0..=1] == "IO" {
-            let efficacy_s = &s[2..=8].parse::<usize>().ok()?;
-            let efficacy = if *efficacy_s == 0 {
-                LawEfficacy::CabinetOrder
-            } else if *efficacy_s == 1000000 {
-                LawEfficacy::Law
-            } else {
-                return None;
-            };
-            let num = s[9..=11].parse::<usize>().ok()?;
-            Some(ImperialOrder { efficacy, num })
-        } else if &s[0..=1] == "DF" {
-            let efficacy_s = &s[2..=8].parse::<usize>().ok()?;
-            let efficacy = if *efficacy_s == 0 {
-                LawEfficacy::CabinetOrder
-            } else if *efficacy_s == 1000000 {
-                LawEfficacy::Law
-            } else {
-                return None;
-            };
-            let num = s[9..=11].parse::<usize>().ok()?;
-            Some(DajokanFukoku { efficacy, num })
-        } else if &s[0..=1] == "DT" {
-            let efficacy_s = &s[2..=8].parse::<usize>().ok()?;
-            let efficacy = if *efficacy_s == 0 {
-                LawEfficacy::CabinetOrder
-            } else if *efficacy_s == 1000000 {
-                LawEfficacy::Law
-            } else {
-                return None;
-            };
-            let num = s[9..=11].parse::<usize>().ok()?;
-            Some(DajokanTasshi { efficacy, num })
-        } else if &s[0..=1] == "DH" {
-            let efficacy_s = &s[2..=8].parse::<usize>().ok()?;
-            let efficacy = if *efficacy_s == 0 {
-                LawEfficacy::CabinetOrder
-            } else if *efficacy_s == 1000000 {
-                LawEfficacy::Law
-            } else {
-                return None;
-            };
-            let num = s[9..=11].parse::<usize>().ok()?;
-            Some(DajokanHutatsu { efficacy, num })
-        } else if &s[0..=0] == "M" {
-            let ministry = Ministry::from_id_str(s).ok()?;
-            let num = s[9..=11].parse::<usize>().ok()?;
-            Some(MinistryOrder { ministry, num })
-        } else if &s[0..=3] == "RJNJ" {
-            let kind = s[4..=5].parse::<usize>().ok()?;
-            let kind_serial_number = s[6..=8].parse::<usize>().ok()?;
-            let amendment_serial_number = s[9..=11].parse::<usize>().ok()?;
-            Some(Jinjin {
-                kind,
-                kind_serial_number,
-                amendment_serial_number,
-            })
-        } else if &s[0..=3] == "RPMD" {
-            let month = s[4..=5].parse::<usize>().ok()?;
-            let day = s[6..=7].parse::<usize>().ok()?;
-            let num = s[8..=11].parse::<usize>().ok()?;
-            Some(PrimeMinisterDecision { month, day, num })
-        } else if &s[0..=0] == "R" {
-            let institution_s = &s[1..=8].parse::<usize>().ok()?;
-            let institution = Institution::from_int(*institution_s)?;
-            let num = s[9..=11].parse::<usize>().ok()?;
-            Some(Regulation { institution, num })
-        } else {
-            None
+            return Ok(Constitution);
+        }
+        let law_type = match &s[0..2] {
+            "AC" => {
+                let rippou_type = match &s[2..9] {
+                    "0000000" => RippouType::Kakuhou,
+                    "1000000" => RippouType::Syuin,
+                    "0100000" => RippouType::Sanin,
+                    _ => return Err(LawIdError::InvalidRippouType { position: 2 }),
+                };
+                let num = parse_digits(s, 9..12)?;
+                Act { rippou_type, num }
+            }
+            "CO" => CabinetOrder {
+                efficacy: parse_efficacy(s)?,
+                num: parse_digits(s, 9..12)?,
+            },
+            "IO" => ImperialOrder {
+                efficacy: parse_efficacy(s)?,
+                num: parse_digits(s, 9..12)?,
+            },
+            "DF" => DajokanFukoku {
+                efficacy: parse_efficacy(s)?,
+                num: parse_digits(s, 9..12)?,
+            },
+            "DT" => DajokanTasshi {
+                efficacy: parse_efficacy(s)?,
+                num: parse_digits(s, 9..12)?,
+            },
+            "DH" => DajokanHutatsu {
+                efficacy: parse_efficacy(s)?,
+                num: parse_digits(s, 9..12)?,
+            },
+            _ if &s[0..1] == "M" => MinistryOrder {
+                ministry: Ministry::from_id_str(&s[0..9])?,
+                num: parse_digits(s, 9..12)?,
+            },
+            _ if &s[0..4] == "RJNJ" => Jinjin {
+                kind: parse_digits(s, 4..6)?,
+                kind_serial_number: parse_digits(s, 6..9)?,
+                amendment_serial_number: parse_digits(s, 9..12)?,
+            },
+            _ if &s[0..4] == "RPMD" => PrimeMinisterDecision {
+                month: parse_digits(s, 4..6)?,
+                day: parse_digits(s, 6..8)?,
+                num: parse_digits(s, 8..12)?,
+            },
+            _ if &s[0..1] == "R" => {
+                let code = parse_digits(s, 1..9)?;
+                let institution = Institution::from_int(code)
+                    .ok_or(LawIdError::UnknownInstitution { position: 1, code })?;
+                let num = parse_digits(s, 9..12)?;
+                Regulation { institution, num }
+            }
+            _ => return Err(LawIdError::UnknownLawType { position: 0 }),
+        };
+        Ok(law_type)
+    }
+}
+
+impl LawType {
+    /// 並べ替えに使う法令の種別の順位
+    fn type_rank(&self) -> usize {
+        use LawType::*;
+        match self {
+            Constitution => 0,
+            Act { .. } => 1,
+            CabinetOrder { .. } => 2,
+            ImperialOrder { .. } => 3,
+            DajokanFukoku { .. } => 4,
+            DajokanTasshi { .. } => 5,
+            DajokanHutatsu { .. } => 6,
+            MinistryOrder { .. } => 7,
+            Jinjin { .. } => 8,
+            Regulation { .. } => 9,
+            PrimeMinisterDecision { .. } => 10,
+        }
+    }
+
+    /// 「法律」や「府省令」などの法令の種別の名称
+    pub fn name(&self) -> &'static str {
+        use LawType::*;
+        match self {
+            Constitution => "憲法",
+            Act { .. } => "法律",
+            CabinetOrder { .. } => "政令",
+            ImperialOrder { .. } => "勅令",
+            DajokanFukoku { .. } => "太政官布告",
+            DajokanTasshi { .. } => "太政官達",
+            DajokanHutatsu { .. } => "太政官布達",
+            MinistryOrder { .. } => "府省令",
+            Jinjin { .. } => "人事院規則",
+            Regulation { .. } => "規則",
+            PrimeMinisterDecision { .. } => "内閣総理大臣決定",
+        }
+    }
+
+    /// 法令IDで種別を表す「AC」や「M」などの記号
+    pub fn code(&self) -> &'static str {
+        use LawType::*;
+        match self {
+            Constitution => "CONSTITUTION",
+            Act { .. } => "AC",
+            CabinetOrder { .. } => "CO",
+            ImperialOrder { .. } => "IO",
+            DajokanFukoku { .. } => "DF",
+            DajokanTasshi { .. } => "DT",
+            DajokanHutatsu { .. } => "DH",
+            MinistryOrder { .. } => "M",
+            Jinjin { .. } => "RJNJ",
+            Regulation { .. } => "R",
+            PrimeMinisterDecision { .. } => "RPMD",
+        }
+    }
+
+    /// 法令の種別の英語名（「Act」や「Ministerial Ordinance」など）
+    pub fn english_name(&self) -> &'static str {
+        use LawType::*;
+        match self {
+            Constitution => "Constitution",
+            Act { .. } => "Act",
+            CabinetOrder { .. } => "Cabinet Order",
+            ImperialOrder { .. } => "Imperial Ordinance",
+            DajokanFukoku { .. } => "Grand Council of State Proclamation",
+            DajokanTasshi { .. } => "Grand Council of State Notice",
+            DajokanHutatsu { .. } => "Grand Council of State Notification",
+            MinistryOrder { .. } => "Ministerial Ordinance",
+            Jinjin { .. } => "National Personnel Authority Rule",
+            Regulation { .. } => "Rule",
+            PrimeMinisterDecision { .. } => "Prime Minister Decision",
         }
     }
 }
 
+/// 法令の種別，番号の順に比較する
+impl PartialOrd for LawType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LawType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use LawType::*;
+        match (self, other) {
+            (
+                Act {
+                    rippou_type: r1,
+                    num: n1,
+                },
+                Act {
+                    rippou_type: r2,
+                    num: n2,
+                },
+            ) => n1.cmp(n2).then(r1.cmp(r2)),
+            (
+                CabinetOrder {
+                    efficacy: e1,
+                    num: n1,
+                },
+                CabinetOrder {
+                    efficacy: e2,
+                    num: n2,
+                },
+            )
+            | (
+                ImperialOrder {
+                    efficacy: e1,
+                    num: n1,
+                },
+                ImperialOrder {
+                    efficacy: e2,
+                    num: n2,
+                },
+            )
+            | (
+                DajokanFukoku {
+                    efficacy: e1,
+                    num: n1,
+                },
+                DajokanFukoku {
+                    efficacy: e2,
+                    num: n2,
+                },
+            )
+            | (
+                DajokanTasshi {
+                    efficacy: e1,
+                    num: n1,
+                },
+                DajokanTasshi {
+                    efficacy: e2,
+                    num: n2,
+                },
+            )
+            | (
+                DajokanHutatsu {
+                    efficacy: e1,
+                    num: n1,
+                },
+                DajokanHutatsu {
+                    efficacy: e2,
+                    num: n2,
+                },
+            ) => n1.cmp(n2).then(e1.cmp(e2)),
+            (
+                MinistryOrder {
+                    ministry: m1,
+                    num: n1,
+                },
+                MinistryOrder {
+                    ministry: m2,
+                    num: n2,
+                },
+            ) => m1.cmp(m2).then(n1.cmp(n2)),
+            (
+                Jinjin {
+                    kind: k1,
+                    kind_serial_number: s1,
+                    amendment_serial_number: a1,
+                },
+                Jinjin {
+                    kind: k2,
+                    kind_serial_number: s2,
+                    amendment_serial_number: a2,
+                },
+            ) => (k1, s1, a1).cmp(&(k2, s2, a2)),
+            (
+                Regulation {
+                    institution: i1,
+                    num: n1,
+                },
+                Regulation {
+                    institution: i2,
+                    num: n2,
+                },
+            ) => i1.to_int().cmp(&i2.to_int()).then(n1.cmp(n2)),
+            (
+                PrimeMinisterDecision {
+                    month: m1,
+                    day: d1,
+                    num: n1,
+                },
+                PrimeMinisterDecision {
+                    month: m2,
+                    day: d2,
+                    num: n2,
+                },
+            ) => (m1, d1, n1).cmp(&(m2, d2, n2)),
+            _ => self.type_rank().cmp(&other.type_rank()),
+        }
+    }
+}
+
+impl fmt::Display for LawType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_id_str())
+    }
+}
+
+impl FromStr for LawType {
+    type Err = LawIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_id_str(s)
+    }
+}
+
+impl_str_conversions!(LawType);
+
+/// 法令IDのうち，法令の効力を表す7文字を解析する
+fn parse_efficacy(s: &str) -> Result<LawEfficacy, LawIdError> {
+    match &s[2..9] {
+        "0000000" => Ok(LawEfficacy::CabinetOrder),
+        "1000000" => Ok(LawEfficacy::Law),
+        _ => Err(LawIdError::InvalidEfficacy { position: 2 }),
+    }
+}
+
 /// 法令ID： <https://elaws.e-gov.go.jp/file/LawIdNamingConvention.pdf>を参照
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+///
+/// 元号，年，法令の種別，番号の順に比較するため，並べ替えると年代順になる
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(remote = "Self")
+)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LawId {
     pub wareki: Wareki,
+    #[cfg_attr(feature = "serde", serde(with = "LawType"))]
     pub law_type: LawType,
 }
 
@@ -1926,19 +1228,35 @@ impl LawId {
             self.law_type.to_id_str()
         )
     }
-    pub fn from_id_str(s: &str) -> Option<Self> {
-        let era_n = &s[0..=0].parse::<usize>().ok()?;
-        let era = Era::from_number(*era_n)?;
-        let year = &s[1..=2].parse::<usize>().ok()?;
-        let type_str = &s[3..=14];
-        let law_type = LawType::from_id_str(type_str)?;
-        Some(Self {
-            wareki: Wareki::new(era, *year),
+    /// 「325M50001000004」のような15文字の法令IDから生成
+    pub fn from_id_str(s: &str) -> Result<Self, LawIdError> {
+        check_id_chars(s, 15)?;
+        let era_n = parse_digits(s, 0..1).map_err(|_| LawIdError::InvalidEra { position: 0 })?;
+        let era = Era::from_number(era_n).ok_or(LawIdError::InvalidEra { position: 0 })?;
+        let year = parse_digits(s, 1..3)?;
+        let law_type = LawType::from_id_str(&s[3..15]).map_err(|e| e.offset(3))?;
+        Ok(Self {
+            wareki: Wareki::new(era, year),
             law_type,
         })
     }
 }
 
+impl fmt::Display for LawId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_id_str())
+    }
+}
+
+impl FromStr for LawId {
+    type Err = LawIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_id_str(s)
+    }
+}
+
+impl_str_conversions!(LawId);
+
 #[test]
 fn check_from_str_law_id() {
     let s = "325M50001000004";
@@ -1948,9 +1266,9 @@ fn check_from_str_law_id() {
         LawId {
             wareki: Wareki::new(Era::Showa, 25),
             law_type: LawType::MinistryOrder {
-                ministry: Ministry::M5(vec![
+                ministry: Ministry::M5(MinistrySet::from([
                     M5Ministry::MinistryOfPostsAndTelecommunicationsOrdinance
-                ]),
+                ])),
                 num: 4
             }
         }
@@ -1984,9 +1302,9 @@ fn check_from_str_law_id_3() {
         LawId {
             wareki: Wareki::new(Era::Reiwa, 5),
             law_type: LawType::MinistryOrder {
-                ministry: Ministry::M6(vec![
+                ministry: Ministry::M6(MinistrySet::from([
                     M6Ministry::MinistryOfEconomyAndTradeAndIndustryOrdinance
-                ]),
+                ])),
                 num: 60
             }
         }
@@ -2003,11 +1321,11 @@ fn check_from_str_law_id_4() {
         LawId {
             wareki: Wareki::new(Era::Reiwa, 5),
             law_type: LawType::MinistryOrder {
-                ministry: Ministry::M6(vec![
+                ministry: Ministry::M6(MinistrySet::from([
                     M6Ministry::MinistryOfTheEnvironmentOrdinance,
                     M6Ministry::MinistryOfForeignAffairsOrdinance,
                     M6Ministry::ReconstructionAgencyOrdinance,
-                ]),
+                ])),
                 num: 60
             }
         }
@@ -2017,7 +1335,7 @@ fn check_from_str_law_id_4() {
 
 #[test]
 fn check_from_str_law_id_lst() {
-    let v = vec![
+    let v = [
         "325M50001000004",
         "345AC0000000089",
         "505M60000400060",
@@ -2030,3 +1348,206 @@ fn check_from_str_law_id_lst() {
         assert_eq!(s, &s2);
     }
 }
+
+#[test]
+fn check_from_str_law_id_err() {
+    assert_eq!(
+        LawId::from_id_str("令和"),
+        Err(LawIdError::NonAscii { position: 0 })
+    );
+    assert_eq!(
+        LawId::from_id_str("325M5"),
+        Err(LawIdError::InvalidLength {
+            position: 5,
+            expected: 15,
+            found: 5
+        })
+    );
+    assert_eq!(
+        LawId::from_id_str("925M50001000004"),
+        Err(LawIdError::InvalidEra { position: 0 })
+    );
+    assert_eq!(
+        LawId::from_id_str("3X5M50001000004"),
+        Err(LawIdError::InvalidDigit { position: 1 })
+    );
+    assert_eq!(
+        LawId::from_id_str("325ZZ0001000004"),
+        Err(LawIdError::UnknownLawType { position: 3 })
+    );
+    assert_eq!(
+        LawId::from_id_str("345AC0010000089"),
+        Err(LawIdError::InvalidRippouType { position: 5 })
+    );
+    assert_eq!(
+        LawId::from_id_str("325M70001000004"),
+        Err(LawIdError::InvalidMinistryPeriod { position: 4 })
+    );
+    assert_eq!(
+        LawId::from_id_str("325M58000000004"),
+        Err(LawIdError::InvalidMinistryBit {
+            position: 5,
+            bit: 28
+        })
+    );
+    assert_eq!(
+        LawId::from_id_str("326R00000099009"),
+        Err(LawIdError::UnknownInstitution {
+            position: 4,
+            code: 99
+        })
+    );
+    assert_eq!(
+        LawId::from_id_str("325M5000100000あ"),
+        Err(LawIdError::NonAscii { position: 14 })
+    );
+}
+
+#[test]
+fn check_parse_digits_overflow() {
+    assert_eq!(parse_digits("0012", 0..4), Ok(12));
+    let s = "99999999999999999999999999";
+    assert_eq!(
+        parse_digits(s, 0..s.len()),
+        Err(LawIdError::InvalidDigit { position: 19 })
+    );
+}
+
+#[test]
+fn check_ministry_period() {
+    assert_eq!(
+        Ministry::from_name("昭和二十年厚生省令"),
+        Err(LawIdError::AmbiguousMinistryPeriod {
+            position: 0,
+            candidates: vec![
+                Ministry::M2(MinistrySet::from([
+                    M2Ministry::MinistryOfHealthAndWelfareOrdinance
+                ])),
+                Ministry::M3(MinistrySet::from([
+                    M3Ministry::MinistryOfHealthAndWelfareOrdinance
+                ])),
+            ]
+        })
+    );
+    assert_eq!(
+        Ministry::from_name("昭和二十年十二月一日厚生省令"),
+        Ok(Ministry::M3(MinistrySet::from([
+            M3Ministry::MinistryOfHealthAndWelfareOrdinance
+        ])))
+    );
+    assert_eq!(
+        Ministry::from_name("昭和二十年運輸通信省令"),
+        Ok(Ministry::M2(MinistrySet::from([
+            M2Ministry::MinistryOfTransportAndCommunicationsOrdinance
+        ])))
+    );
+    assert_eq!(
+        Ministry::from_name_at("厚生省令", ymd(1945, 11, 30)),
+        Ok(Ministry::M2(MinistrySet::from([
+            M2Ministry::MinistryOfHealthAndWelfareOrdinance
+        ])))
+    );
+    assert_eq!(
+        Ministry::periods_of_wareki(Wareki::new(Era::Heisei, 13)),
+        vec![5, 6]
+    );
+    assert_eq!(Ministry::period_of_date(ymd(2001, 1, 6)), Some(6));
+}
+
+#[test]
+fn check_ministry_from_name() {
+    assert_eq!(
+        Ministry::from_name("昭和二十五年郵政省令"),
+        Ok(Ministry::M5(MinistrySet::from([
+            M5Ministry::MinistryOfPostsAndTelecommunicationsOrdinance
+        ])))
+    );
+    assert_eq!(
+        Ministry::from_name("令和元年存在しない省令"),
+        Err(LawIdError::UnknownMinistryName { position: 12 })
+    );
+}
+
+#[test]
+fn check_law_id_str_traits() {
+    let s = "325M50001000004";
+    let law_id: LawId = s.parse().unwrap();
+    assert_eq!(law_id.to_string(), s);
+    assert_eq!(LawId::try_from(s), Ok(law_id));
+    assert_eq!(LawId::try_from(String::from(s)), Ok(law_id));
+    assert_eq!(String::from(law_id), s);
+    assert_eq!(
+        "M50001000".parse::<Ministry>().unwrap().to_string(),
+        "M50001000"
+    );
+    assert_eq!(
+        "AC0000000089".parse::<LawType>().unwrap().to_string(),
+        "AC0000000089"
+    );
+    assert_eq!("令和元年".parse(), Ok(Wareki::new(Era::Reiwa, 1)));
+    assert_eq!(Wareki::new(Era::Reiwa, 1).to_string(), "令和元年");
+    assert_eq!(Wareki::new(Era::Showa, 25).to_string(), "昭和25年");
+    assert!("令和元年法律".parse::<Wareki>().is_err());
+}
+
+#[test]
+fn check_law_id_ord() {
+    let mut v = [
+        "505M60000400060",
+        "345AC1000000002",
+        "345AC0000000089",
+        "505AC0000000003",
+        "325M50001000004",
+        "345CO0000000001",
+        "345AC0000000003",
+    ]
+    .map(|s| LawId::from_id_str(s).unwrap());
+    v.sort();
+    assert_eq!(
+        v.map(|id| id.to_id_str()),
+        [
+            "325M50001000004",
+            "345AC1000000002",
+            "345AC0000000003",
+            "345AC0000000089",
+            "345CO0000000001",
+            "505AC0000000003",
+            "505M60000400060",
+        ]
+    );
+    let map: std::collections::BTreeMap<LawId, usize> = v.iter().cloned().zip(0..).collect();
+    assert_eq!(map.keys().next().unwrap().to_id_str(), "325M50001000004");
+}
+
+#[test]
+fn check_ministry_names() {
+    let m = M6Ministry::MinistryOfHealthAndLaborAndWelfareOrdinance;
+    assert_eq!(m.name(), "厚生労働省令");
+    assert_eq!(m.agency_name(), "厚生労働省");
+    assert_eq!(m.kana(), "こうせいろうどうしょう");
+    assert_eq!(m.english_name(), "Ministry of Health, Labour and Welfare");
+    assert_eq!(M1Ministry::CabinetOrder.agency_name(), "内閣");
+    assert_eq!(
+        M1Ministry::MinistryOfJusticeOrdinanceHei.agency_name(),
+        "司法省"
+    );
+    assert_eq!(Institution::BoardOfAudit.name(), "会計検査院規則");
+    assert_eq!(Institution::BoardOfAudit.english_name(), "Board of Audit");
+
+    fn check_all<T: MinistryContents>() {
+        for m in (1..=28).filter_map(T::from_int) {
+            assert!(!m.kana().is_empty(), "{}", m.name());
+            assert!(!m.english_name().is_empty(), "{}", m.name());
+        }
+    }
+    check_all::<M1Ministry>();
+    check_all::<M2Ministry>();
+    check_all::<M3Ministry>();
+    check_all::<M4Ministry>();
+    check_all::<M5Ministry>();
+    check_all::<M6Ministry>();
+    for i in (1..=28).filter_map(Institution::from_int) {
+        assert!(!i.kana().is_empty(), "{}", i.name());
+        assert!(!i.english_name().is_empty(), "{}", i.name());
+    }
+}
@@ -0,0 +1,980 @@
+//! 府・省と機関の一覧
+//!
+//! 法令IDでのビット（機関番号），法令番号に現れる名称，府省・機関の読みと英語名，区分の期間はこのファイルの表にのみ記述し，
+//! 列挙型とその変換はすべて表からマクロで生成する．
+//! 新しい府省・機関を追加するときは表に一行加えればよい．
+//!
+//! 英語名は日本法令外国語訳データベース（<https://www.japaneselawtranslation.go.jp/>）の表記に従う
+
+use crate::date::ymd;
+use crate::{Date, LawIdError, Wareki, check_id_chars};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{BitAnd, BitOr, BitXor, Sub};
+
+/// 府・省に共通化させる
+pub trait MinistryContents: Sized + Copy + 'static {
+    /// 区分に属するすべての府・省令（ビットの小さい順）
+    const ALL: &'static [Self];
+    /// 事前に用意されている府・省令のビットに変換する．
+    /// <https://laws.e-gov.go.jp/file/LawIdNamingConvention.pdf>の9ページ参照．
+    fn to_int(&self) -> usize;
+    /// 事前に用意されている府・省令のビットから戻す
+    /// <https://laws.e-gov.go.jp/file/LawIdNamingConvention.pdf>の9ページ参照．
+    fn from_int(n: usize) -> Option<Self>;
+    /// 「厚生労働省令」や「公正取引委員会規則」などの法令番号に現れる名称
+    fn name(&self) -> &'static str;
+    /// 「厚生労働省」や「公正取引委員会」などの府省の名称
+    fn agency_name(&self) -> &'static str {
+        match split_ordinance_name(self.name()) {
+            ("閣", _) => "内閣",
+            (issuer, _) => issuer,
+        }
+    }
+    /// 府省の名称の読み（「こうせいろうどうしょう」など）
+    fn kana(&self) -> &'static str;
+    /// 日本法令外国語訳データベースでの府省の英語名（「Ministry of Health, Labour and Welfare」など）
+    fn english_name(&self) -> &'static str;
+    /// 区分の開始年月日
+    fn start() -> Date;
+    /// 区分の終了年月日
+    /// 現在も続いている区分は`None`
+    fn end() -> Option<Date>;
+    /// 該当する年代かどうかの判定
+    fn applicable(date: Date) -> bool {
+        Self::start() <= date && Self::end().is_none_or(|end| date <= end)
+    }
+    /// 和暦から該当する年代かどうかの判定
+    fn applicable_wareki(wareki: Wareki) -> bool {
+        Self::start().year <= wareki.to_ad()
+            && Self::end().is_none_or(|end| wareki.to_ad() <= end.year)
+    }
+}
+
+/// 複数省庁管轄の法令の府・省の集合
+///
+/// 法令IDの16進数7桁の箇所と同じ28ビットのビット列で持つため，
+/// 府省の順序や重複は区別されず，同じ法令IDになるものは等しくなる．
+/// 比較はビット列の大小による
+pub struct MinistrySet<T> {
+    bits: u32,
+    marker: PhantomData<T>,
+}
+
+impl<T: MinistryContents> MinistrySet<T> {
+    /// 空の集合
+    pub const fn new() -> Self {
+        Self {
+            bits: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// 法令IDでのビット列から生成する
+    ///
+    /// 区分に存在しないビットが立っている場合は`None`を返す
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        (bits & !Self::all().bits == 0).then_some(Self {
+            bits,
+            marker: PhantomData,
+        })
+    }
+
+    /// 区分に属するすべての府・省の集合
+    pub fn all() -> Self {
+        T::ALL.iter().copied().collect()
+    }
+
+    /// 法令IDでのビット列
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// 府・省のビット
+    fn bit(m: T) -> u32 {
+        1 << (m.to_int() - 1)
+    }
+
+    /// 府・省を加える．既に含まれていた場合は`false`を返す
+    pub fn insert(&mut self, m: T) -> bool {
+        let contained = self.contains(m);
+        self.bits |= Self::bit(m);
+        !contained
+    }
+
+    /// 府・省を取り除く．含まれていなかった場合は`false`を返す
+    pub fn remove(&mut self, m: T) -> bool {
+        let contained = self.contains(m);
+        self.bits &= !Self::bit(m);
+        contained
+    }
+
+    pub fn contains(&self, m: T) -> bool {
+        self.bits & Self::bit(m) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// ビットの小さい順（国家行政組織法の別表の順）に府・省を返す
+    pub fn iter(&self) -> MinistrySetIter<T> {
+        MinistrySetIter {
+            bits: self.bits,
+            marker: PhantomData,
+        }
+    }
+
+    /// 和集合
+    pub fn union(&self, other: &Self) -> Self {
+        self.with_bits(self.bits | other.bits)
+    }
+
+    /// 積集合
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.with_bits(self.bits & other.bits)
+    }
+
+    /// 差集合
+    pub fn difference(&self, other: &Self) -> Self {
+        self.with_bits(self.bits & !other.bits)
+    }
+
+    /// 対称差
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.with_bits(self.bits ^ other.bits)
+    }
+
+    /// `other`にすべて含まれているかどうか
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.bits & !other.bits == 0
+    }
+
+    /// `other`を含んでいるかどうか
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// 共通の府・省が無いかどうか
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.bits & other.bits == 0
+    }
+
+    fn with_bits(&self, bits: u32) -> Self {
+        Self {
+            bits,
+            marker: PhantomData,
+        }
+    }
+
+    /// 複数省庁管轄の法令の法令IDのうち，省庁を表す16進数7桁の箇所
+    pub fn to_id_str(&self) -> String {
+        format!("{:07X}", self.bits)
+    }
+
+    /// 複数省庁管轄の法令の法令ID文字列のうち，省庁を表す16進数7桁の箇所から担当省庁を計算する
+    pub fn from_id_str(hex_s: &str) -> Result<Self, LawIdError> {
+        check_id_chars(hex_s, 7)?;
+        if let Some(position) = hex_s.bytes().position(|b| !b.is_ascii_hexdigit()) {
+            return Err(LawIdError::InvalidDigit { position });
+        }
+        let n = u32::from_str_radix(hex_s, 16).unwrap();
+        // 上位の桁にある大きいビットから確かめ，最初に見つかった不正な桁の位置を返す
+        if let Some(bit) = (1..=28)
+            .rev()
+            .find(|bit| n & (1 << (bit - 1)) != 0 && T::from_int(*bit).is_none())
+        {
+            return Err(LawIdError::InvalidMinistryBit {
+                position: 6 - (bit - 1) / 4,
+                bit,
+            });
+        }
+        Ok(Self::from_bits(n).unwrap())
+    }
+
+    /// 「厚生労働省・農林水産省令」のような複数省庁管轄の法令の名称を生成する
+    ///
+    /// 省庁はビットの小さい順（国家行政組織法の別表の順）に並べる
+    pub fn joined_name(&self) -> String {
+        let mut issuers = Vec::new();
+        let mut kind = "";
+        for m in self.iter() {
+            let (issuer, k) = split_ordinance_name(m.name());
+            issuers.push(issuer);
+            kind = k;
+        }
+        format!("{}{kind}", issuers.join("・"))
+    }
+
+    /// 「厚生労働省令」や「厚生労働省・農林水産省令」などから導き出す
+    ///
+    /// 「・」で区切られた各位置で最も長く一致する府省の名称を採る．
+    /// 「陸軍省令甲」のように種別に「甲」などの記号が付くものは，記号まで一致するものだけを含める．
+    /// 名称の末尾の「令」や「規則」は省略してもよいが，
+    /// 省略したために「陸軍省令甲」と「陸軍省令乙」のように複数の府・省に当たる場合は`LawIdError::AmbiguousMinistry`になる
+    pub fn from_name(name: &str) -> Result<Self, LawIdError> {
+        let mut issuers = Vec::new();
+        let mut position = 0;
+        loop {
+            let issuer = T::ALL
+                .iter()
+                .map(|m| split_ordinance_name(m.name()).0)
+                .filter(|issuer| name[position..].starts_with(issuer))
+                .max_by_key(|issuer| issuer.len())
+                .ok_or(LawIdError::UnknownMinistryName { position })?;
+            issuers.push((position, issuer));
+            position += issuer.len();
+            if name[position..].starts_with('・') {
+                position += '・'.len_utf8();
+            } else {
+                break;
+            }
+        }
+        let kind = &name[position..];
+        let mut set = Self::new();
+        for (issuer_position, issuer) in issuers {
+            let matched: Vec<T> = T::ALL
+                .iter()
+                .copied()
+                .filter(|m| {
+                    let (m_issuer, m_kind) = split_ordinance_name(m.name());
+                    m_issuer == issuer && (kind.is_empty() || kind == m_kind)
+                })
+                .collect();
+            match matched.as_slice() {
+                [] => return Err(LawIdError::UnknownMinistryName { position }),
+                [m] => {
+                    set.insert(*m);
+                }
+                _ => {
+                    return Err(LawIdError::AmbiguousMinistry {
+                        position: issuer_position,
+                        candidates: matched.iter().map(|m| m.name()).collect(),
+                    });
+                }
+            }
+        }
+        Ok(set)
+    }
+}
+
+// `T`に境界を課さないよう，導出せずに実装する
+impl<T> Clone for MinistrySet<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MinistrySet<T> {}
+
+impl<T> PartialEq for MinistrySet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<T> Eq for MinistrySet<T> {}
+
+impl<T> PartialOrd for MinistrySet<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for MinistrySet<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bits.cmp(&other.bits)
+    }
+}
+
+impl<T> Hash for MinistrySet<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits.hash(state);
+    }
+}
+
+impl<T: MinistryContents> Default for MinistrySet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: MinistryContents + fmt::Debug> fmt::Debug for MinistrySet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: MinistryContents> FromIterator<T> for MinistrySet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: MinistryContents> Extend<T> for MinistrySet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for m in iter {
+            self.insert(m);
+        }
+    }
+}
+
+impl<T: MinistryContents, const N: usize> From<[T; N]> for MinistrySet<T> {
+    fn from(l: [T; N]) -> Self {
+        l.into_iter().collect()
+    }
+}
+
+impl<T: MinistryContents> IntoIterator for MinistrySet<T> {
+    type Item = T;
+    type IntoIter = MinistrySetIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: MinistryContents> IntoIterator for &MinistrySet<T> {
+    type Item = T;
+    type IntoIter = MinistrySetIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: MinistryContents> BitOr for MinistrySet<T> {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(&rhs)
+    }
+}
+
+impl<T: MinistryContents> BitAnd for MinistrySet<T> {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(&rhs)
+    }
+}
+
+impl<T: MinistryContents> BitXor for MinistrySet<T> {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self {
+        self.symmetric_difference(&rhs)
+    }
+}
+
+impl<T: MinistryContents> Sub for MinistrySet<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(&rhs)
+    }
+}
+
+/// JSONなどでは府・省の列として表す
+#[cfg(feature = "serde")]
+impl<T: MinistryContents + Serialize> Serialize for MinistrySet<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: MinistryContents + Deserialize<'de>> Deserialize<'de> for MinistrySet<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(Self::from_iter)
+    }
+}
+
+/// `MinistrySet`の府・省をビットの小さい順に返す
+pub struct MinistrySetIter<T> {
+    bits: u32,
+    marker: PhantomData<T>,
+}
+
+impl<T: MinistryContents> Iterator for MinistrySetIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.bits == 0 {
+            return None;
+        }
+        let bit = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        T::from_int(bit as usize + 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T: MinistryContents> DoubleEndedIterator for MinistrySetIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.bits == 0 {
+            return None;
+        }
+        let bit = 31 - self.bits.leading_zeros();
+        self.bits &= !(1 << bit);
+        T::from_int(bit as usize + 1)
+    }
+}
+
+impl<T: MinistryContents> ExactSizeIterator for MinistrySetIter<T> {}
+
+impl<T> Clone for MinistrySetIter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            bits: self.bits,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// 「厚生労働省令」を「厚生労働省」と「令」に，「司法省令丙」を「司法省」と「令丙」に分ける
+pub(crate) fn split_ordinance_name(name: &str) -> (&str, &str) {
+    if let Some(issuer) = name.strip_suffix("規則") {
+        (issuer, "規則")
+    } else if let Some(i) = name.rfind('令') {
+        name.split_at(i)
+    } else {
+        (name, "")
+    }
+}
+
+/// 区分ごとの府・省の表から列挙型と`MinistryContents`の実装を生成する
+///
+/// 各行は「バリアント = ビット => (法令番号に現れる名称, 府省の読み, 府省の英語名)」で，
+/// 名称はそのままバリアントのドキュメントになる
+macro_rules! ministry_table {
+    (
+        $(#[$meta:meta])*
+        $name:ident: $start:expr => $end:expr;
+        $(
+            $(#[$vmeta:meta])*
+            $variant:ident = $bit:literal => ($jname:literal, $kana:literal, $english:literal),
+        )*
+    ) => {
+        $(#[$meta])*
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+        pub enum $name {
+            $(
+                #[doc = $jname]
+                $(#[$vmeta])*
+                $variant,
+            )*
+        }
+
+        impl MinistryContents for $name {
+            const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            fn to_int(&self) -> usize {
+                match self {
+                    $(Self::$variant => $bit,)*
+                }
+            }
+
+            // ビットの重複は到達不能なパターンとしてコンパイルエラーになる
+            #[deny(unreachable_patterns)]
+            fn from_int(n: usize) -> Option<Self> {
+                match n {
+                    $($bit => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $jname,)*
+                }
+            }
+
+            fn kana(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $kana,)*
+                }
+            }
+
+            fn english_name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $english,)*
+                }
+            }
+
+            fn start() -> Date {
+                $start
+            }
+
+            fn end() -> Option<Date> {
+                $end
+            }
+        }
+    };
+}
+
+/// 機関の表から`Institution`とその変換を生成する
+///
+/// 各行は「バリアント = 機関番号 => (法令番号に現れる名称, 機関の読み, 機関の英語名)」
+macro_rules! institution_table {
+    (
+        $(#[$meta:meta])*
+        $name:ident;
+        $(
+            $(#[$vmeta:meta])*
+            $variant:ident = $code:literal => ($jname:literal, $kana:literal, $english:literal),
+        )*
+    ) => {
+        $(#[$meta])*
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+        pub enum $name {
+            $(
+                #[doc = $jname]
+                $(#[$vmeta])*
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// すべての機関（機関番号の小さい順）
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            /// 法令IDでの機関番号に変換する
+            pub fn to_int(&self) -> usize {
+                match self {
+                    $(Self::$variant => $code,)*
+                }
+            }
+
+            /// 法令IDでの機関番号から戻す
+            #[deny(unreachable_patterns)]
+            pub fn from_int(n: usize) -> Option<Self> {
+                match n {
+                    $($code => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// 「会計検査院規則」などの法令番号に現れる名称
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $jname,)*
+                }
+            }
+
+            /// 機関の名称の読み（「かいけいけんさいん」など）
+            pub fn kana(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $kana,)*
+                }
+            }
+
+            /// 日本法令外国語訳データベースでの機関の英語名（「Board of Audit」など）
+            pub fn english_name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $english,)*
+                }
+            }
+        }
+    };
+}
+
+ministry_table! {
+    /// M1時（1869年7月8日〜1943年10月31日）での府・省
+    M1Ministry: ymd(1869, 7, 8) => Some(ymd(1943, 10, 31));
+    CabinetOrder = 1 => ("閣令", "ないかく", "Cabinet"),
+    ImperialHouseholdOrdinance = 2 => ("宮内省令", "くないしょう", "Imperial Household Ministry"),
+    GreaterEastAsiaMinisterialOrdinance = 3 => ("大東亜省令", "だいとうあしょう", "Ministry of Greater East Asia"),
+    MinistryOfTheInteriorOrdinance = 4 => ("内務省令", "ないむしょう", "Home Ministry"),
+    MinistryOfJusticeOrdinance = 5 => ("司法省令", "しほうしょう", "Ministry of Justice"),
+    MinistryOfForeignAffairsOrdinance = 6 => ("外務省令", "がいむしょう", "Ministry of Foreign Affairs"),
+    MinistryOfFinanceOrdinance = 7 => ("大蔵省令", "おおくらしょう", "Ministry of Finance"),
+    MinistryOfEducationOrdinance = 8 => ("文部省令", "もんぶしょう", "Ministry of Education"),
+    MinistryOfHealthAndWelfareOrdinance = 9 => ("厚生省令", "こうせいしょう", "Ministry of Health and Welfare"),
+    MinistryOfAgricultureAndCommerceOrdinance = 10 => ("農商務省令", "のうしょうむしょう", "Ministry of Agriculture and Commerce"),
+    MinistryOfCommerceAndIndustryOrdinance = 11 => ("商工省令", "しょうこうしょう", "Ministry of Commerce and Industry"),
+    RailwayMinisterialOrdinance = 12 => ("鉄道省令", "てつどうしょう", "Ministry of Railways"),
+    MinistryOfCommunicationsOrdinance = 13 => ("逓信省令", "ていしんしょう", "Ministry of Communications"),
+    MinistryOfTheArmyOrdinanceA = 14 => ("陸軍省令甲", "りくぐんしょう", "Ministry of the Army"),
+    NavyMinisterialOrdinance = 15 => ("海軍省令", "かいぐんしょう", "Ministry of the Navy"),
+    MinistryOfTheArmyOrdinanceB = 16 => ("陸軍省令乙", "りくぐんしょう", "Ministry of the Army"),
+    MinistryOfAgricultureAndForestryOrdinance = 17 => ("農林省令", "のうりんしょう", "Ministry of Agriculture and Forestry"),
+    MinistryOfLandDevelopmentOrdinanceA = 18 => ("拓殖務省令", "たくしょくむしょう", "Ministry of Colonial Affairs"),
+    MinistryOfLandDevelopmentOrdinanceB = 19 => ("拓務省令", "たくむしょう", "Ministry of Colonial Affairs"),
+    MinistryOfAgricultureAndCommerceOrdinanceTemporary = 20 => ("農商務省令臨", "のうしょうむしょう", "Ministry of Agriculture and Commerce"),
+    /// 例：明治十九年度以降科料罰金徴収及民事刑事其他一時預リ金取扱方（明治19年4月7日司法省令丙第1号）<https://hourei.ndl.go.jp/#/detail?lawId=0000000249&searchDiv=1&current=1>
+    MinistryOfJusticeOrdinanceHei = 21 => ("司法省令丙", "しほうしょう", "Ministry of Justice"),
+}
+
+ministry_table! {
+    /// M2時（1943年11月1日〜1945年11月30日）での府・省
+    M2Ministry: ymd(1943, 11, 1) => Some(ymd(1945, 11, 30));
+    CabinetOrder = 1 => ("閣令", "ないかく", "Cabinet"),
+    ImperialHouseholdOrdinance = 2 => ("宮内省令", "くないしょう", "Imperial Household Ministry"),
+    GreaterEastAsiaMinisterialOrdinance = 3 => ("大東亜省令", "だいとうあしょう", "Ministry of Greater East Asia"),
+    MinistryOfTheInteriorOrdinance = 4 => ("内務省令", "ないむしょう", "Home Ministry"),
+    MinistryOfJusticeOrdinance = 5 => ("司法省令", "しほうしょう", "Ministry of Justice"),
+    MinistryOfForeignAffairsOrdinance = 6 => ("外務省令", "がいむしょう", "Ministry of Foreign Affairs"),
+    MinistryOfFinanceOrdinance = 7 => ("大蔵省令", "おおくらしょう", "Ministry of Finance"),
+    MinistryOfEducationOrdinance = 8 => ("文部省令", "もんぶしょう", "Ministry of Education"),
+    MinistryOfHealthAndWelfareOrdinance = 9 => ("厚生省令", "こうせいしょう", "Ministry of Health and Welfare"),
+    MinistryOfAgricultureAndCommerceOrdinance = 10 => ("農商務省令", "のうしょうむしょう", "Ministry of Agriculture and Commerce"),
+    MinistryOfCommerceAndIndustryOrdinance = 11 => ("商工省令", "しょうこうしょう", "Ministry of Commerce and Industry"),
+    MinistryOfTransportOrdinance = 12 => ("運輸省令", "うんゆしょう", "Ministry of Transport"),
+    MinistryOfTransportAndCommunicationsOrdinance = 13 => ("運輸通信省令", "うんゆつうしんしょう", "Ministry of Transportation and Communications"),
+    MinistryOfTheArmyOrdinanceA = 14 => ("陸軍省令", "りくぐんしょう", "Ministry of the Army"),
+    NavyMinisterialOrdinance = 15 => ("海軍省令", "かいぐんしょう", "Ministry of the Navy"),
+    OrdinanceOfTheMinistryOfMunitions = 16 => ("軍需省令", "ぐんじゅしょう", "Ministry of Munitions"),
+    MinistryOfAgricultureAndForestryOrdinance = 17 => ("農林省令", "のうりんしょう", "Ministry of Agriculture and Forestry"),
+}
+
+ministry_table! {
+    /// M3時（1945年12月1日〜1947年5月2日）での府・省
+    M3Ministry: ymd(1945, 12, 1) => Some(ymd(1947, 5, 2));
+    CabinetOrder = 1 => ("閣令", "ないかく", "Cabinet"),
+    ImperialHouseholdOrdinance = 2 => ("宮内省令", "くないしょう", "Imperial Household Ministry"),
+    EconomicStabilityHeadquartersOrdinance = 3 => ("経済安定本部令", "けいざいあんていほんぶ", "Economic Stabilization Board"),
+    MinistryOfTheInteriorOrdinance = 4 => ("内務省令", "ないむしょう", "Home Ministry"),
+    MinistryOfJusticeOrdinance = 5 => ("司法省令", "しほうしょう", "Ministry of Justice"),
+    MinistryOfForeignAffairsOrdinance = 6 => ("外務省令", "がいむしょう", "Ministry of Foreign Affairs"),
+    MinistryOfFinanceOrdinance = 7 => ("大蔵省令", "おおくらしょう", "Ministry of Finance"),
+    MinistryOfEducationOrdinance = 8 => ("文部省令", "もんぶしょう", "Ministry of Education"),
+    MinistryOfHealthAndWelfareOrdinance = 9 => ("厚生省令", "こうせいしょう", "Ministry of Health and Welfare"),
+    MinistryOfAgricultureAndForestryOrdinance = 10 => ("農林省令", "のうりんしょう", "Ministry of Agriculture and Forestry"),
+    MinistryOfCommerceAndIndustryOrdinance = 11 => ("商工省令", "しょうこうしょう", "Ministry of Commerce and Industry"),
+    MinistryOfTransportOrdinance = 12 => ("運輸省令", "うんゆしょう", "Ministry of Transport"),
+    MinistryOfCommunicationsOrdinance = 13 => ("逓信省令", "ていしんしょう", "Ministry of Communications"),
+    FirstMinisterialOrdinanceForDemobilization = 14 => ("第一復員省令", "だいいちふくいんしょう", "First Demobilization Ministry"),
+    SecondMinisterialOrdinanceForDemobilization = 15 => ("第二復員省令", "だいにふくいんしょう", "Second Demobilization Ministry"),
+    PriceAgencyOrdinance = 16 => ("物価庁令", "ぶっかちょう", "Price Agency"),
+    CentralLaborRelationsCommissionRules = 21 => ("中央労働委員会規則", "ちゅうおうろうどういいんかい", "Central Labour Relations Commission"),
+}
+
+ministry_table! {
+    /// M4時（1947年5月3日〜1949年5月31日）での府・省
+    M4Ministry: ymd(1947, 5, 3) => Some(ymd(1949, 5, 31));
+    LegalAffairsAgencyOrdinance = 1 => ("法務庁令", "ほうむちょう", "Attorney General's Office"),
+    PrimeMinistersOfficeOrdinance = 2 => ("総理庁令", "そうりちょう", "Prime Minister's Office"),
+    EconomicStabilityHeadquartersOrdinance = 3 => ("経済安定本部令", "けいざいあんていほんぶ", "Economic Stabilization Board"),
+    MinistryOfTheInteriorOrdinance = 4 => ("内務省令", "ないむしょう", "Home Ministry"),
+    MinistryOfJusticeOrdinance = 5 => ("司法省令", "しほうしょう", "Ministry of Justice"),
+    MinistryOfForeignAffairsOrdinance = 6 => ("外務省令", "がいむしょう", "Ministry of Foreign Affairs"),
+    MinistryOfFinanceOrdinance = 7 => ("大蔵省令", "おおくらしょう", "Ministry of Finance"),
+    MinistryOfEducationOrdinance = 8 => ("文部省令", "もんぶしょう", "Ministry of Education"),
+    MinistryOfHealthAndWelfareOrdinance = 9 => ("厚生省令", "こうせいしょう", "Ministry of Health and Welfare"),
+    MinistryOfAgricultureAndForestryOrdinance = 10 => ("農林省令", "のうりんしょう", "Ministry of Agriculture and Forestry"),
+    MinistryOfInternationalTradeAndIndustryOrdinance = 11 => ("通商産業省令", "つうしょうさんぎょうしょう", "Ministry of International Trade and Industry"),
+    MinistryOfTransportOrdinance = 12 => ("運輸省令", "うんゆしょう", "Ministry of Transport"),
+    MinistryOfCommunicationsOrdinance = 13 => ("逓信省令", "ていしんしょう", "Ministry of Communications"),
+    MinistryOfLaborOrdinance = 14 => ("労働省令", "ろうどうしょう", "Ministry of Labour"),
+    MinistryOfConstructionOrdinance = 15 => ("建設省令", "けんせつしょう", "Ministry of Construction"),
+    PriceAgencyOrdinance = 16 => ("物価庁令", "ぶっかちょう", "Price Agency"),
+    MinistryOfCommerceAndIndustryOrdinance = 17 => ("商工省令", "しょうこうしょう", "Ministry of Commerce and Industry"),
+    CentralLaborRelationsCommissionRules = 21 => ("中央労働委員会規則", "ちゅうおうろうどういいんかい", "Central Labour Relations Commission"),
+    FairTradeCommissionRules = 22 => ("公正取引委員会規則", "こうせいとりひきいいんかい", "Japan Fair Trade Commission"),
+    NationalPublicSafetyCommissionRegulations = 23 => ("国家公安委員会規則", "こっかこうあんいいんかい", "National Public Safety Commission"),
+}
+
+ministry_table! {
+    /// M5時（1949年6月1日〜2001年1月5日）での府・省
+    M5Ministry: ymd(1949, 6, 1) => Some(ymd(2001, 1, 5));
+    LegalAffairsAgencyOrdinance = 1 => ("法務府令", "ほうむふ", "Attorney General's Office"),
+    PrimeMinistersOfficeOrdinance = 2 => ("総理府令", "そうりふ", "Prime Minister's Office"),
+    EconomicStabilityHeadquartersOrdinance = 3 => ("経済安定本部令", "けいざいあんていほんぶ", "Economic Stabilization Board"),
+    MinistryOfHomeAffairsOrdinance = 4 => ("自治省令", "じちしょう", "Ministry of Home Affairs"),
+    MinistryOfJusticeOrdinance = 5 => ("法務省令", "ほうむしょう", "Ministry of Justice"),
+    MinistryOfForeignAffairsOrdinance = 6 => ("外務省令", "がいむしょう", "Ministry of Foreign Affairs"),
+    MinistryOfFinanceOrdinance = 7 => ("大蔵省令", "おおくらしょう", "Ministry of Finance"),
+    MinistryOfEducationOrdinance = 8 => ("文部省令", "もんぶしょう", "Ministry of Education"),
+    MinistryOfHealthAndWelfareOrdinance = 9 => ("厚生省令", "こうせいしょう", "Ministry of Health and Welfare"),
+    MinistryOfAgricultureAndForestryAndFisheriesOrdinance = 10 => ("農林水産省令", "のうりんすいさんしょう", "Ministry of Agriculture, Forestry and Fisheries"),
+    MinistryOfInternationalTradeAndIndustryOrdinance = 11 => ("通商産業省令", "つうしょうさんぎょうしょう", "Ministry of International Trade and Industry"),
+    MinistryOfTransportOrdinance = 12 => ("運輸省令", "うんゆしょう", "Ministry of Transport"),
+    MinistryOfPostsAndTelecommunicationsOrdinance = 13 => ("郵政省令", "ゆうせいしょう", "Ministry of Posts and Telecommunications"),
+    MinistryOfLaborOrdinance = 14 => ("労働省令", "ろうどうしょう", "Ministry of Labour"),
+    MinistryOfConstructionOrdinance = 15 => ("建設省令", "けんせつしょう", "Ministry of Construction"),
+    PriceAgencyOrdinance = 16 => ("物価庁令", "ぶっかちょう", "Price Agency"),
+    MinistryOfAgricultureAndForestryOrdinance = 17 => ("農林省令", "のうりんしょう", "Ministry of Agriculture and Forestry"),
+    TelecommunicationsMinisterialOrdinance = 18 => ("電気通信省令", "でんきつうしんしょう", "Ministry of Telecommunications"),
+    CentralMinistriesAndAgenciesReformPromotionHeadquartersOrdinance = 19 => ("中央省庁等改革推進本部令", "ちゅうおうしょうちょうとうかいかくすいしんほんぶ", "Headquarters for the Promotion of Central Government Reform"),
+    RadioRegulatoryCommissionRules = 20 => ("電波監理委員会規則", "でんぱかんりいいんかい", "Radio Regulatory Commission"),
+    CentralLaborRelationsCommissionRules = 21 => ("中央労働委員会規則", "ちゅうおうろうどういいんかい", "Central Labour Relations Commission"),
+    FairTradeCommissionRules = 22 => ("公正取引委員会規則", "こうせいとりひきいいんかい", "Japan Fair Trade Commission"),
+    NationalPublicSafetyCommissionRegulations = 23 => ("国家公安委員会規則", "こっかこうあんいいんかい", "National Public Safety Commission"),
+    PollutionAdjustmentCommitteeRules = 24 => ("公害等調整委員会規則", "こうがいとうちょうせいいいんかい", "Environmental Dispute Coordination Commission"),
+    PublicSafetyReviewCommitteeRules = 25 => ("公安審査委員会規則", "こうあんしんさいいんかい", "Public Security Examination Commission"),
+}
+
+ministry_table! {
+    /// M6時（2001年1月6日〜）での府・省
+    M6Ministry: ymd(2001, 1, 6) => None;
+    CabinetSecretariatOrdinance = 1 => ("内閣官房令", "ないかくかんぼう", "Cabinet Secretariat"),
+    PrimeMinistersOfficeOrdinance = 2 => ("内閣府令", "ないかくふ", "Cabinet Office"),
+    ReconstructionAgencyOrdinance = 3 => ("復興庁令", "ふっこうちょう", "Reconstruction Agency"),
+    MinistryOfHomeAffairsOrdinance = 4 => ("総務省令", "そうむしょう", "Ministry of Internal Affairs and Communications"),
+    MinistryOfJusticeOrdinance = 5 => ("法務省令", "ほうむしょう", "Ministry of Justice"),
+    MinistryOfForeignAffairsOrdinance = 6 => ("外務省令", "がいむしょう", "Ministry of Foreign Affairs"),
+    MinistryOfFinanceOrdinance = 7 => ("財務省令", "ざいむしょう", "Ministry of Finance"),
+    MinistryOfEducationAndCultureAndSportsAndScienceAndTechnologyOrdinance = 8 => ("文部科学省令", "もんぶかがくしょう", "Ministry of Education, Culture, Sports, Science and Technology"),
+    MinistryOfHealthAndLaborAndWelfareOrdinance = 9 => ("厚生労働省令", "こうせいろうどうしょう", "Ministry of Health, Labour and Welfare"),
+    MinistryOfAgricultureAndForestryAndFisheriesOrdinance = 10 => ("農林水産省令", "のうりんすいさんしょう", "Ministry of Agriculture, Forestry and Fisheries"),
+    MinistryOfEconomyAndTradeAndIndustryOrdinance = 11 => ("経済産業省令", "けいざいさんぎょうしょう", "Ministry of Economy, Trade and Industry"),
+    MinistryOfLandAndInfrastructureAndTransportAndTourismOrdinance = 12 => ("国土交通省令", "こくどこうつうしょう", "Ministry of Land, Infrastructure, Transport and Tourism"),
+    MinistryOfTheEnvironmentOrdinance = 13 => ("環境省令", "かんきょうしょう", "Ministry of the Environment"),
+    MinistryOfDefenseOrdinance = 14 => ("防衛省令", "ぼうえいしょう", "Ministry of Defense"),
+    DigitalAgencyOrdinance = 15 => ("デジタル庁令", "でじたるちょう", "Digital Agency"),
+    /// 平成28年1月1日より前は特定個人情報保護委員会規則
+    SpecificPersonalInformationProtectionCommissionRules = 18 => ("個人情報保護委員会規則", "こじんじょうほうほごいいんかい", "Personal Information Protection Commission"),
+    JapanTransportSafetyBoardRegulations = 19 => ("運輸安全委員会規則", "うんゆあんぜんいいんかい", "Japan Transport Safety Board"),
+    NuclearRegulationAuthorityRegulations = 20 => ("原子力規制委員会規則", "げんしりょくきせいいいんかい", "Nuclear Regulation Authority"),
+    CentralLaborRelationsCommissionRules = 21 => ("中央労働委員会規則", "ちゅうおうろうどういいんかい", "Central Labour Relations Commission"),
+    FairTradeCommissionRules = 22 => ("公正取引委員会規則", "こうせいとりひきいいんかい", "Japan Fair Trade Commission"),
+    NationalPublicSafetyCommissionRegulations = 23 => ("国家公安委員会規則", "こっかこうあんいいんかい", "National Public Safety Commission"),
+    PollutionAdjustmentCommitteeRules = 24 => ("公害等調整委員会規則", "こうがいとうちょうせいいいんかい", "Environmental Dispute Coordination Commission"),
+    PublicSafetyReviewCommitteeRules = 25 => ("公安審査委員会規則", "こうあんしんさいいんかい", "Public Security Examination Commission"),
+    CasinoManagementCommitteeRules = 26 => ("カジノ管理委員会規則", "かじのかんりいいんかい", "Casino Regulatory Commission"),
+}
+
+institution_table! {
+    /// 機関名
+    Institution;
+    BoardOfAudit = 1 => ("会計検査院規則", "かいけいけんさいん", "Board of Audit"),
+    CoastGuard = 2 => ("海上保安庁規則", "かいじょうほあんちょう", "Japan Coast Guard"),
+    ScienceCouncilOfJapan = 3 => ("日本学術会議規則", "にほんがくじゅつかいぎ", "Science Council of Japan"),
+    LandAdjustmentCommittee = 4 => ("土地調整委員会規則", "とちちょうせいいいんかい", "Land Coordination Commission"),
+    FinancialReconstructionCommittee = 5 => ("金融再生委員会規則", "きんゆうさいせいいいんかい", "Financial Reconstruction Commission"),
+    MetropolitanAreaDevelopmentCommittee = 6 => ("首都圏整備委員会規則", "しゅとけんせいびいいんかい", "National Capital Region Development Commission"),
+    LocalFinanceCommittee = 7 => ("地方財政委員会規則", "ちほうざいせいいいんかい", "Local Finance Commission"),
+    BarExaminationManagementCommittee = 8 => ("司法試験管理委員会規則", "しほうしけんかんりいいんかい", "Bar Examination Administration Commission"),
+    CertifiedPublicAccountantManagementCommittee = 9 => ("公認会計士管理委員会規則", "こうにんかいけいしかんりいいんかい", "Certified Public Accountants Administration Commission"),
+    ForeignInvestmentCommittee = 10 => ("外資委員会規則", "がいしいいんかい", "Foreign Investment Commission"),
+    CulturalPropertiesProtectionCommittee = 11 => ("文化財保護委員会規則", "ぶんかざいほごいいんかい", "Commission for Protection of Cultural Properties"),
+    JapaneseNationalCommissionForUNESCO = 12 => ("日本ユネスコ国内委員会規則", "にほんゆねすここくないいいんかい", "Japanese National Commission for UNESCO"),
+    SupremeCourt = 13 => ("最高裁判所規則", "さいこうさいばんしょ", "Supreme Court"),
+    HouseOfRepresentatives = 14 => ("衆議院規則", "しゅうぎいん", "House of Representatives"),
+    HouseOfCouncilors = 15 => ("参議院規則", "さんぎいん", "House of Councillors"),
+    SeafarersCentralLaborCommittee = 16 => ("船員中央労働委員会規則", "せんいんちゅうおうろうどういいんかい", "Central Labour Relations Commission for Seafarers"),
+    /// 法令ID命名規約の表では機関番号8と同じ名称が割り当てられている．
+    /// 名称からは区別できないため，`Institution::from_name`は`LawIdError::AmbiguousInstitution`を返す
+    BarExaminationManagementCommitteeB = 17 => ("司法試験管理委員会規則", "しほうしけんかんりいいんかい", "Bar Examination Administration Commission"),
+    RadioRegulatoryCommission = 18 => ("電波監理委員会規則", "でんぱかんりいいんかい", "Radio Regulatory Commission"),
+    CasinoManagementCommittee = 19 => ("カジノ管理委員会規則", "かじのかんりいいんかい", "Casino Regulatory Commission"),
+}
+
+impl Institution {
+    /// 「会計検査院」などの機関の名称
+    pub fn agency_name(&self) -> &'static str {
+        split_ordinance_name(self.name()).0
+    }
+
+    /// 「会計検査院規則」または「会計検査院」から導き出す
+    ///
+    /// 名称の全体が機関の名称と一致する必要がある．
+    /// 同じ名称の機関が複数ある場合は`LawIdError::AmbiguousInstitution`になる
+    pub fn from_name(name: &str) -> Result<Self, LawIdError> {
+        let candidates = Self::candidates_from_name(name)?;
+        match candidates.as_slice() {
+            [institution] => Ok(*institution),
+            _ => Err(LawIdError::AmbiguousInstitution {
+                position: 0,
+                candidates,
+            }),
+        }
+    }
+
+    /// 「会計検査院規則」または「会計検査院」から考えられる機関をすべて導き出す（機関番号の小さい順）
+    pub fn candidates_from_name(name: &str) -> Result<Vec<Self>, LawIdError> {
+        let candidates: Vec<Self> = Self::ALL
+            .iter()
+            .copied()
+            .filter(|i| name == i.name() || name == i.agency_name())
+            .collect();
+        if candidates.is_empty() {
+            return Err(LawIdError::UnknownMinistryName { position: 0 });
+        }
+        Ok(candidates)
+    }
+}
+
+#[test]
+fn check_from_name_tokens() {
+    assert_eq!(
+        MinistrySet::from_name("農商務省令臨"),
+        Ok(MinistrySet::from([
+            M1Ministry::MinistryOfAgricultureAndCommerceOrdinanceTemporary
+        ]))
+    );
+    assert_eq!(
+        MinistrySet::from_name("運輸通信省令"),
+        Ok(MinistrySet::from([
+            M2Ministry::MinistryOfTransportAndCommunicationsOrdinance
+        ]))
+    );
+    assert_eq!(
+        MinistrySet::from_name("農林水産省・厚生労働省令"),
+        Ok(MinistrySet::from([
+            M6Ministry::MinistryOfAgricultureAndForestryAndFisheriesOrdinance,
+            M6Ministry::MinistryOfHealthAndLaborAndWelfareOrdinance,
+        ]))
+    );
+    assert_eq!(
+        MinistrySet::<M1Ministry>::from_name("内閣府令"),
+        Err(LawIdError::UnknownMinistryName { position: 0 })
+    );
+    assert_eq!(
+        MinistrySet::<M6Ministry>::from_name("厚生労働省・存在しない省令"),
+        Err(LawIdError::UnknownMinistryName { position: 18 })
+    );
+    assert_eq!(
+        MinistrySet::<M1Ministry>::from_name("陸軍省令丁"),
+        Err(LawIdError::UnknownMinistryName { position: 9 })
+    );
+    assert_eq!(
+        MinistrySet::<M1Ministry>::from_name("海軍省・陸軍省"),
+        Err(LawIdError::AmbiguousMinistry {
+            position: 12,
+            candidates: vec!["陸軍省令甲", "陸軍省令乙"]
+        })
+    );
+    assert_eq!(
+        MinistrySet::from_name("陸軍省令乙"),
+        Ok(MinistrySet::from([M1Ministry::MinistryOfTheArmyOrdinanceB]))
+    );
+    assert_eq!(
+        MinistrySet::from_name("厚生省"),
+        Ok(MinistrySet::from([
+            M1Ministry::MinistryOfHealthAndWelfareOrdinance
+        ]))
+    );
+}
+
+#[test]
+fn check_table_round_trip() {
+    fn check_all<T: MinistryContents + std::fmt::Debug + PartialEq>() {
+        for &m in T::ALL {
+            assert_eq!(T::from_int(m.to_int()), Some(m));
+            assert_eq!(MinistrySet::from_name(m.name()), Ok(MinistrySet::from([m])));
+        }
+        assert_eq!((1..=28).filter_map(T::from_int).count(), T::ALL.len());
+    }
+    check_all::<M1Ministry>();
+    check_all::<M2Ministry>();
+    check_all::<M3Ministry>();
+    check_all::<M4Ministry>();
+    check_all::<M5Ministry>();
+    check_all::<M6Ministry>();
+    for &i in Institution::ALL {
+        assert_eq!(Institution::from_int(i.to_int()), Some(i));
+    }
+    assert_eq!(
+        MinistrySet::from_name("復興庁令"),
+        Ok(MinistrySet::from([
+            M6Ministry::ReconstructionAgencyOrdinance
+        ]))
+    );
+    assert_eq!(
+        Institution::from_int(17),
+        Some(Institution::BarExaminationManagementCommitteeB)
+    );
+    assert_eq!(
+        Institution::from_name("船員中央労働委員会規則"),
+        Ok(Institution::SeafarersCentralLaborCommittee)
+    );
+    assert_eq!(
+        Institution::from_name("会計検査院"),
+        Ok(Institution::BoardOfAudit)
+    );
+    assert_eq!(
+        Institution::from_name("偽会計検査院規則"),
+        Err(LawIdError::UnknownMinistryName { position: 0 })
+    );
+    assert_eq!(
+        Institution::from_name("司法試験管理委員会規則"),
+        Err(LawIdError::AmbiguousInstitution {
+            position: 0,
+            candidates: vec![
+                Institution::BarExaminationManagementCommittee,
+                Institution::BarExaminationManagementCommitteeB
+            ]
+        })
+    );
+}
+
+#[test]
+fn check_ministry_set() {
+    use M6Ministry::*;
+    let a = MinistrySet::from([
+        ReconstructionAgencyOrdinance,
+        MinistryOfTheEnvironmentOrdinance,
+        MinistryOfForeignAffairsOrdinance,
+    ]);
+    let b = MinistrySet::from([
+        MinistryOfForeignAffairsOrdinance,
+        ReconstructionAgencyOrdinance,
+        MinistryOfTheEnvironmentOrdinance,
+        MinistryOfForeignAffairsOrdinance,
+    ]);
+    assert_eq!(a, b);
+    assert_eq!(a.len(), 3);
+    assert_eq!(a.to_id_str(), "0001024");
+    assert_eq!(MinistrySet::from_id_str("0001024"), Ok(a));
+    assert_eq!(
+        a.iter().collect::<Vec<_>>(),
+        vec![
+            ReconstructionAgencyOrdinance,
+            MinistryOfForeignAffairsOrdinance,
+            MinistryOfTheEnvironmentOrdinance,
+        ]
+    );
+    assert_eq!(a.joined_name(), "復興庁・外務省・環境省令");
+    assert!(a.contains(MinistryOfForeignAffairsOrdinance));
+    assert!(!a.contains(MinistryOfJusticeOrdinance));
+
+    let c = MinistrySet::from([
+        MinistryOfForeignAffairsOrdinance,
+        MinistryOfJusticeOrdinance,
+    ]);
+    assert_eq!(
+        a & c,
+        MinistrySet::from([MinistryOfForeignAffairsOrdinance])
+    );
+    assert_eq!((a | c).len(), 4);
+    assert_eq!(
+        a - c,
+        MinistrySet::from([
+            ReconstructionAgencyOrdinance,
+            MinistryOfTheEnvironmentOrdinance
+        ])
+    );
+    assert_eq!((a ^ c).len(), 3);
+    assert!((a & c).is_subset(&a));
+    assert!(a.is_disjoint(&MinistrySet::new()));
+
+    let mut d = MinistrySet::new();
+    assert!(d.insert(MinistryOfJusticeOrdinance));
+    assert!(!d.insert(MinistryOfJusticeOrdinance));
+    assert!(d.remove(MinistryOfJusticeOrdinance));
+    assert!(d.is_empty());
+
+    assert_eq!(
+        MinistrySet::<M6Ministry>::all().len(),
+        M6Ministry::ALL.len()
+    );
+    assert_eq!(MinistrySet::<M6Ministry>::from_bits(1 << 27), None);
+    assert_eq!(
+        MinistrySet::<M6Ministry>::from_id_str("8000000"),
+        Err(LawIdError::InvalidMinistryBit {
+            position: 0,
+            bit: 28
+        })
+    );
+}
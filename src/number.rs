@@ -0,0 +1,106 @@
+//! 法令番号などに現れる数字の解析
+
+use kansuji::Kansuji;
+
+/// 全角数字を半角数字に置き換える
+pub(crate) fn to_hankaku_digits(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '０'..='９' => char::from(b'0' + (c as u32 - '０' as u32) as u8),
+            _ => c,
+        })
+        .collect()
+}
+
+/// 位取りの漢数字（「一四」など）の一文字を数値にする
+fn kansuji_digit(c: char) -> Option<usize> {
+    match c {
+        '〇' | '零' => Some(0),
+        '一' => Some(1),
+        '二' => Some(2),
+        '三' => Some(3),
+        '四' => Some(4),
+        '五' => Some(5),
+        '六' => Some(6),
+        '七' => Some(7),
+        '八' => Some(8),
+        '九' => Some(9),
+        _ => None,
+    }
+}
+
+/// 「六十」，「一四」，「60」，「６０」などの数字を解析する
+///
+/// 「十」「百」「千」を含まない漢数字は「一四」（14）のような位取りの表記として扱う
+pub(crate) fn parse_number(s: &str) -> Option<usize> {
+    if s.is_empty() {
+        return None;
+    }
+    let s = to_hankaku_digits(s);
+    if s.chars().all(|c| c.is_ascii_digit()) {
+        s.parse().ok()
+    } else if s.chars().all(|c| kansuji_digit(c).is_some()) {
+        s.chars().try_fold(0_usize, |n, c| {
+            n.checked_mul(10)?.checked_add(kansuji_digit(c)?)
+        })
+    } else {
+        let n: u128 = Kansuji::try_from(s.as_str()).ok()?.into();
+        usize::try_from(n).ok()
+    }
+}
+
+/// 数字の表記
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub enum Numeral {
+    /// 「六十」
+    #[default]
+    Kansuji,
+    /// 「60」
+    Arabic,
+    /// 「６０」
+    FullWidth,
+}
+
+/// 数字を指定された表記で出力する
+pub(crate) fn format_number(n: usize, numeral: Numeral) -> String {
+    match numeral {
+        Numeral::Kansuji if n == 0 => String::from("〇"),
+        Numeral::Kansuji => Kansuji::from(n as u128).to_string(),
+        Numeral::Arabic => n.to_string(),
+        Numeral::FullWidth => n
+            .to_string()
+            .chars()
+            .map(|c| char::from_u32('０' as u32 + (c as u32 - '0' as u32)).unwrap())
+            .collect(),
+    }
+}
+
+/// 数字を「一四」のような位取りの漢数字で出力する
+pub(crate) fn format_positional_kansuji(n: usize) -> String {
+    const DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+    n.to_string()
+        .bytes()
+        .map(|b| DIGITS[(b - b'0') as usize])
+        .collect()
+}
+
+#[test]
+fn check_format_number() {
+    assert_eq!(format_number(131, Numeral::Kansuji), "百三十一");
+    assert_eq!(format_number(131, Numeral::Arabic), "131");
+    assert_eq!(format_number(131, Numeral::FullWidth), "１３１");
+    assert_eq!(format_positional_kansuji(14), "一四");
+    assert_eq!(format_positional_kansuji(10), "一〇");
+}
+
+#[test]
+fn check_parse_number() {
+    assert_eq!(parse_number("六十"), Some(60));
+    assert_eq!(parse_number("百三十一"), Some(131));
+    assert_eq!(parse_number("一四"), Some(14));
+    assert_eq!(parse_number("一〇"), Some(10));
+    assert_eq!(parse_number("60"), Some(60));
+    assert_eq!(parse_number("６０"), Some(60));
+    assert_eq!(parse_number(""), None);
+    assert_eq!(parse_number("六a"), None);
+}
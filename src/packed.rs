@@ -0,0 +1,307 @@
+//! 法令IDの`u64`への詰め込み
+//!
+//! 上位のビットから次の順に並べる．
+//!
+//! | ビット数 | 内容 |
+//! | --- | --- |
+//! | 3 | 元号（`Era::to_number`） |
+//! | 7 | 年 |
+//! | 4 | 法令の種別（並べ替えの順位） |
+//! | 50 | 種別ごとの番号など |
+//!
+//! 種別ごとの部分も比較の順に上位のビットから並べるため，
+//! 詰め込んだ値の大小は`LawId`の比較（年代順）と一致する
+
+use crate::*;
+
+/// 種別ごとの部分のビット数
+const PAYLOAD_BITS: u32 = 50;
+
+/// ビット列を上位から順に組み立てる
+struct Packer(u64);
+
+impl Packer {
+    /// `bits`ビットの値を追加する．収まらない場合は`None`を返す
+    fn push(self, value: usize, bits: u32) -> Option<Self> {
+        let value = u64::try_from(value).ok().filter(|v| *v >> bits == 0)?;
+        Some(Self((self.0 << bits) | value))
+    }
+}
+
+/// ビット列を上位から順に取り出す
+struct Unpacker {
+    value: u64,
+    remaining: u32,
+}
+
+impl Unpacker {
+    fn new(value: u64, bits: u32) -> Self {
+        Self {
+            value,
+            remaining: bits,
+        }
+    }
+
+    fn pop(&mut self, bits: u32) -> usize {
+        self.remaining -= bits;
+        ((self.value >> self.remaining) & ((1 << bits) - 1)) as usize
+    }
+
+    /// 使われていない下位のビットがすべて0であることを確認する
+    fn finish(self) -> Option<()> {
+        (self.value & ((1 << self.remaining) - 1) == 0).then_some(())
+    }
+}
+
+impl LawType {
+    /// 種別ごとの部分を`PAYLOAD_BITS`ビットの左詰めで作る
+    fn pack_payload(&self) -> Option<u64> {
+        use LawType::*;
+        let p = Packer(0);
+        let (p, bits) = match self {
+            Constitution => (p, 0),
+            Act { rippou_type, num } => (p.push(*num, 10)?.push(*rippou_type as usize, 2)?, 12),
+            CabinetOrder { efficacy, num }
+            | ImperialOrder { efficacy, num }
+            | DajokanFukoku { efficacy, num }
+            | DajokanTasshi { efficacy, num }
+            | DajokanHutatsu { efficacy, num } => {
+                (p.push(*num, 10)?.push(*efficacy as usize, 1)?, 11)
+            }
+            MinistryOrder { ministry, num } => (
+                p.push(ministry.period(), 3)?
+                    .push(ministry.bits() as usize, 28)?
+                    .push(*num, 10)?,
+                41,
+            ),
+            Jinjin {
+                kind,
+                kind_serial_number,
+                amendment_serial_number,
+            } => (
+                p.push(*kind, 7)?
+                    .push(*kind_serial_number, 10)?
+                    .push(*amendment_serial_number, 10)?,
+                27,
+            ),
+            Regulation { institution, num } => {
+                (p.push(institution.to_int(), 27)?.push(*num, 10)?, 37)
+            }
+            PrimeMinisterDecision { month, day, num } => {
+                (p.push(*month, 7)?.push(*day, 7)?.push(*num, 14)?, 28)
+            }
+        };
+        Some(p.0 << (PAYLOAD_BITS - bits))
+    }
+
+    /// 種別の順位と種別ごとの部分から戻す
+    fn unpack_payload(rank: usize, payload: u64) -> Option<Self> {
+        use LawType::*;
+        let mut u = Unpacker::new(payload, PAYLOAD_BITS);
+        let efficacy = |n| match n {
+            0 => Some(LawEfficacy::CabinetOrder),
+            1 => Some(LawEfficacy::Law),
+            _ => None,
+        };
+        let law_type = match rank {
+            0 => Constitution,
+            1 => {
+                let num = u.pop(10);
+                let rippou_type = match u.pop(2) {
+                    0 => RippouType::Kakuhou,
+                    1 => RippouType::Syuin,
+                    2 => RippouType::Sanin,
+                    _ => return None,
+                };
+                Act { rippou_type, num }
+            }
+            2..=6 => {
+                let num = u.pop(10);
+                let efficacy = efficacy(u.pop(1))?;
+                match rank {
+                    2 => CabinetOrder { efficacy, num },
+                    3 => ImperialOrder { efficacy, num },
+                    4 => DajokanFukoku { efficacy, num },
+                    5 => DajokanTasshi { efficacy, num },
+                    _ => DajokanHutatsu { efficacy, num },
+                }
+            }
+            7 => {
+                let period = u.pop(3);
+                let bits = u.pop(28) as u32;
+                let ministry = match period {
+                    1 => Ministry::M1(MinistrySet::from_bits(bits)?),
+                    2 => Ministry::M2(MinistrySet::from_bits(bits)?),
+                    3 => Ministry::M3(MinistrySet::from_bits(bits)?),
+                    4 => Ministry::M4(MinistrySet::from_bits(bits)?),
+                    5 => Ministry::M5(MinistrySet::from_bits(bits)?),
+                    6 => Ministry::M6(MinistrySet::from_bits(bits)?),
+                    _ => return None,
+                };
+                MinistryOrder {
+                    ministry,
+                    num: u.pop(10),
+                }
+            }
+            8 => Jinjin {
+                kind: u.pop(7),
+                kind_serial_number: u.pop(10),
+                amendment_serial_number: u.pop(10),
+            },
+            9 => Regulation {
+                institution: Institution::from_int(u.pop(27))?,
+                num: u.pop(10),
+            },
+            10 => PrimeMinisterDecision {
+                month: u.pop(7),
+                day: u.pop(7),
+                num: u.pop(14),
+            },
+            _ => return None,
+        };
+        u.finish()?;
+        Some(law_type)
+    }
+}
+
+impl LawId {
+    /// 法令IDを`u64`に詰め込む
+    ///
+    /// 詰め込んだ値の大小は`LawId`の比較と一致する．
+    /// 年や番号などが15文字の法令IDの各欄の桁数に収まらない場合は`None`を返す
+    pub fn to_u64(&self) -> Option<u64> {
+        if MAX_2_DIGITS < self.wareki.get_year()
+            || self
+                .law_type
+                .id_fields()
+                .iter()
+                .any(|(_, value, max)| max < value)
+        {
+            return None;
+        }
+        let head = Packer(0)
+            .push(self.wareki.get_era().to_number(), 3)?
+            .push(self.wareki.get_year(), 7)?
+            .push(self.law_type.type_rank(), 4)?;
+        Some((head.0 << PAYLOAD_BITS) | self.law_type.pack_payload()?)
+    }
+
+    /// `to_u64`で詰め込んだ値から戻す
+    ///
+    /// `to_u64`で作られない値は`None`を返す
+    pub fn from_u64(n: u64) -> Option<Self> {
+        let mut u = Unpacker::new(n, 64);
+        let era = Era::from_number(u.pop(3))?;
+        let year = u.pop(7);
+        let rank = u.pop(4);
+        if 99 < year {
+            return None;
+        }
+        let law_type = LawType::unpack_payload(rank, n & ((1 << PAYLOAD_BITS) - 1))?;
+        if law_type
+            .id_fields()
+            .iter()
+            .any(|(_, value, max)| max < value)
+        {
+            return None;
+        }
+        Some(Self {
+            wareki: Wareki::new(era, year),
+            law_type,
+        })
+    }
+}
+
+#[test]
+fn check_u64_round_trip() {
+    let ids = [
+        "321CONSTITUTION",
+        "322AC0000000067",
+        "505AC1000000001",
+        "505AC0100000001",
+        "345CO1000000001",
+        "322IO0000000001",
+        "104DF0000000001",
+        "105DT1000000001",
+        "106DH0000000001",
+        "325M50001000004",
+        "505M60001024060",
+        "410RJNJ14007000",
+        "505R00000001001",
+        "412RPMD02290001",
+    ];
+    for s in ids {
+        let law_id = LawId::from_id_str(s).unwrap();
+        let n = law_id.to_u64().unwrap();
+        assert_eq!(LawId::from_u64(n), Some(law_id), "{s}");
+    }
+}
+
+#[test]
+fn check_u64_order() {
+    let mut ids: Vec<LawId> = [
+        "505M60001024060",
+        "505M60000000001",
+        "325M50001000004",
+        "322AC0000000067",
+        "322AC1000000066",
+        "322AC0000000066",
+        "412RPMD02290001",
+        "412RPMD01010002",
+        "505R00000013001",
+        "505R00000002999",
+        "410RJNJ14007000",
+        "410RJNJ09008001",
+        "321CONSTITUTION",
+        "104DF0000000001",
+        "105DH0000000001",
+    ]
+    .iter()
+    .map(|s| LawId::from_id_str(s).unwrap())
+    .collect();
+    let mut packed: Vec<u64> = ids.iter().map(|id| id.to_u64().unwrap()).collect();
+    ids.sort();
+    packed.sort();
+    let unpacked: Vec<LawId> = packed
+        .into_iter()
+        .map(|n| LawId::from_u64(n).unwrap())
+        .collect();
+    assert_eq!(unpacked, ids);
+}
+
+#[test]
+fn check_u64_invalid() {
+    let law_id = LawId {
+        wareki: Wareki::new(Era::Showa, 100),
+        law_type: LawType::Constitution,
+    };
+    assert_eq!(law_id.to_u64(), None);
+    let law_id = LawId {
+        wareki: Wareki::new(Era::Reiwa, 5),
+        law_type: LawType::CabinetOrder {
+            efficacy: LawEfficacy::CabinetOrder,
+            num: 1000,
+        },
+    };
+    assert_eq!(law_id.to_u64(), None);
+    let law_id = LawId {
+        wareki: Wareki::new(Era::Reiwa, 5),
+        law_type: LawType::Jinjin {
+            kind: 100,
+            kind_serial_number: 1,
+            amendment_serial_number: 0,
+        },
+    };
+    assert_eq!(law_id.to_u64(), None);
+    let law_id = LawId::from_id_str("322AC0000000067").unwrap();
+    let n = law_id.to_u64().unwrap();
+    assert_eq!(LawId::from_u64(n | 1), None);
+    // 番号の10ビットに1000を入れた値
+    let n = LawId::from_id_str("505CO0000000999")
+        .unwrap()
+        .to_u64()
+        .unwrap();
+    assert_eq!(LawId::from_u64(n + (1 << 40)), None);
+    assert_eq!(LawId::from_u64(0), None);
+    assert_eq!(LawId::from_u64(u64::MAX), None);
+}
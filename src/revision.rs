@@ -0,0 +1,131 @@
+//! e-Gov法令API Version 2の法令履歴ID
+
+use crate::*;
+
+/// 改正法令の法令IDが無い（制定時の版）ことを表す文字列
+const NO_AMENDMENT: &str = "000000000000000";
+
+/// 「322AC0000000067_20230614_505AC0000000053」のような法令履歴ID
+///
+/// 法令ID，改正の施行日，改正法令の法令IDを`_`でつないだもので，法令の各時点の版を表す．
+/// 法令ID，施行日，改正法令の法令IDの順に比較するため，並べ替えると法令ごとに版の順になる
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LawRevisionId {
+    /// 改正される法令
+    pub law_id: LawId,
+    /// 改正の施行日
+    pub date: Date,
+    /// 改正法令．制定時の版では`None`
+    pub amend_law_id: Option<LawId>,
+}
+
+impl LawRevisionId {
+    pub fn to_id_str(&self) -> String {
+        format!("{}_{}", self.law_id, self.suffix())
+    }
+
+    /// 法令IDより後の「20230614_505AC0000000053」の部分
+    pub(crate) fn suffix(&self) -> String {
+        let amend = match &self.amend_law_id {
+            Some(law_id) => law_id.to_id_str(),
+            None => String::from(NO_AMENDMENT),
+        };
+        format!(
+            "{:04}{:02}{:02}_{amend}",
+            self.date.year, self.date.month, self.date.day
+        )
+    }
+
+    /// 「322AC0000000067_20230614_505AC0000000053」のような40文字の法令履歴IDから生成
+    pub fn from_id_str(s: &str) -> Result<Self, LawIdError> {
+        check_id_chars(s, 40)?;
+        let law_id = LawId::from_id_str(&s[0..15])?;
+        if &s[15..16] != "_" {
+            return Err(LawIdError::InvalidRevisionId { position: 15 });
+        }
+        Self::with_suffix(law_id, &s[16..]).map_err(|e| e.offset(16))
+    }
+
+    /// 法令IDと「20230614_505AC0000000053」の部分から生成
+    pub(crate) fn with_suffix(law_id: LawId, s: &str) -> Result<Self, LawIdError> {
+        check_id_chars(s, 24)?;
+        let date = Date::new_ad(
+            parse_digits(s, 0..4)?,
+            parse_digits(s, 4..6)?,
+            parse_digits(s, 6..8)?,
+        )?;
+        if &s[8..9] != "_" {
+            return Err(LawIdError::InvalidRevisionId { position: 8 });
+        }
+        let amend_law_id = match &s[9..] {
+            NO_AMENDMENT => None,
+            amend => Some(LawId::from_id_str(amend).map_err(|e| e.offset(9))?),
+        };
+        Ok(Self {
+            law_id,
+            date,
+            amend_law_id,
+        })
+    }
+}
+
+impl fmt::Display for LawRevisionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_id_str())
+    }
+}
+
+impl FromStr for LawRevisionId {
+    type Err = LawIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_id_str(s)
+    }
+}
+
+impl_str_conversions!(LawRevisionId);
+
+#[test]
+fn check_law_revision_id() {
+    let s = "322AC0000000067_20230614_505AC0000000053";
+    let revision = LawRevisionId::from_id_str(s).unwrap();
+    assert_eq!(
+        revision,
+        LawRevisionId {
+            law_id: LawId::from_id_str("322AC0000000067").unwrap(),
+            date: Date::new_ad(2023, 6, 14).unwrap(),
+            amend_law_id: Some(LawId::from_id_str("505AC0000000053").unwrap()),
+        }
+    );
+    assert_eq!(revision.to_string(), s);
+    let original: LawRevisionId = "322AC0000000067_19470503_000000000000000".parse().unwrap();
+    assert_eq!(original.amend_law_id, None);
+    assert_eq!(
+        original.to_id_str(),
+        "322AC0000000067_19470503_000000000000000"
+    );
+    assert!(original < revision);
+    assert_eq!(
+        LawRevisionId::from_id_str("322AC0000000067_20230230_505AC0000000053"),
+        Err(LawIdError::InvalidDate { position: 16 })
+    );
+    assert_eq!(
+        LawRevisionId::from_id_str("322AC0000000067-20230614_505AC0000000053"),
+        Err(LawIdError::InvalidRevisionId { position: 15 })
+    );
+    assert_eq!(
+        LawRevisionId::from_id_str("322AC0000000067_20230614_505XX0000000053"),
+        Err(LawIdError::UnknownLawType { position: 28 })
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_law_revision_id_serde() {
+    let revision = LawRevisionId::from_id_str("322AC0000000067_20230614_505AC0000000053").unwrap();
+    let json = serde_json::to_string(&revision).unwrap();
+    assert_eq!(
+        serde_json::from_str::<LawRevisionId>(&json).unwrap(),
+        revision
+    );
+}
@@ -0,0 +1,224 @@
+//! 文章中の法令IDと法令番号の検出
+
+use crate::*;
+use std::ops::Range;
+
+/// 法令番号の数字に使われる文字
+const NUMBER_CHARS: &str = "0-9０-９〇一二三四五六七八九十百千";
+
+/// 見つかった法令IDや法令番号の書き方
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum MatchStyle {
+    /// 「322AC0000000067」のような法令ID
+    LawId,
+    /// 「３２２ＡＣ００００００００６７」のような全角文字の法令ID
+    FullWidthLawId,
+    /// 「昭和二十二年法律第六十七号」や「令和５年法律第６０号」のような法令番号
+    LawNum,
+}
+
+/// 文章中で見つかった法令IDや法令番号
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct LawIdMatch {
+    /// 文章中のバイトの範囲．法令番号の途中の改行も含む
+    pub range: Range<usize>,
+    pub style: MatchStyle,
+    /// 法令ID．法令番号から一意に定まらない場合（法律の閣法・衆法・参法など）は`None`
+    pub law_id: Option<LawId>,
+    /// 考えられる法令IDのすべて
+    pub candidates: Vec<LawId>,
+    /// 「（昭和二十二年法律第六十七号）」のように括弧に囲まれているかどうか
+    pub parenthesized: bool,
+}
+
+impl LawIdMatch {
+    /// 見つかった箇所の文字列
+    pub fn as_str<'a>(&self, text: &'a str) -> &'a str {
+        &text[self.range.clone()]
+    }
+}
+
+/// 文章中の法令IDと法令番号を先頭から順にすべて探す
+///
+/// 法令IDは前後を英数字以外で区切られた15文字のもので，全角の英数字も含む．
+/// 法令番号は漢数字，算用数字，全角数字のいずれでもよく，途中に改行（前後の空白を含む）があってもよい．
+/// 解析できないものは含めない
+pub fn find_law_ids(text: &str) -> impl Iterator<Item = LawIdMatch> {
+    let mut matches = find_ids(text);
+    matches.extend(find_law_nums(text));
+    matches.sort_by_key(|m| m.range.start);
+    for m in &mut matches {
+        m.parenthesized = text[..m.range.start].ends_with(['（', '('])
+            && text[m.range.end..].starts_with(['）', ')']);
+    }
+    matches.into_iter()
+}
+
+/// 全角の英数字を半角にする
+fn to_half_width(c: char) -> Option<char> {
+    match c {
+        '０'..='９' | 'Ａ'..='Ｚ' => char::from_u32(c as u32 - '０' as u32 + '0' as u32),
+        _ => None,
+    }
+}
+
+/// 英数字の並びのうち15文字のものを法令IDとして解析する
+fn find_ids(text: &str) -> Vec<LawIdMatch> {
+    let is_alphanumeric = |c: char| c.is_ascii_alphanumeric() || to_half_width(c).is_some();
+    let mut matches = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if !is_alphanumeric(c) {
+            continue;
+        }
+        let mut token = String::from(to_half_width(c).unwrap_or(c));
+        let mut full_width = c != token.chars().next().unwrap();
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, c)) = chars.peek() {
+            if !is_alphanumeric(c) {
+                break;
+            }
+            let half = to_half_width(c);
+            full_width |= half.is_some();
+            token.push(half.unwrap_or(c));
+            end = i + c.len_utf8();
+            chars.next();
+        }
+        if token.len() != 15 {
+            continue;
+        }
+        if let Ok(law_id) = LawId::from_id_str(&token) {
+            matches.push(LawIdMatch {
+                range: start..end,
+                style: if full_width {
+                    MatchStyle::FullWidthLawId
+                } else {
+                    MatchStyle::LawId
+                },
+                law_id: Some(law_id),
+                candidates: vec![law_id],
+                parenthesized: false,
+            });
+        }
+    }
+    matches
+}
+
+/// 改行とその前後の空白を取り除いた文字列と，その各バイトの元の文字列での位置
+fn remove_line_breaks(text: &str) -> (String, Vec<usize>) {
+    let mut joined = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len());
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c.is_whitespace() {
+            let mut end = i + c.len_utf8();
+            while let Some(&(j, c)) = chars.peek() {
+                if !c.is_whitespace() {
+                    break;
+                }
+                end = j + c.len_utf8();
+                chars.next();
+            }
+            let space = &text[i..end];
+            if space.contains(['\n', '\r']) {
+                continue;
+            }
+            joined.push_str(space);
+            offsets.extend(i..end);
+        } else {
+            joined.push(c);
+            offsets.extend(i..i + c.len_utf8());
+        }
+    }
+    (joined, offsets)
+}
+
+/// 「昭和二十二年法律第六十七号」や「平成十年人事院規則一四―七」のような法令番号
+static LAW_NUM_RE: LazyLock<Regex> = LazyLock::new(|| {
+    let n = NUMBER_CHARS;
+    Regex::new(&format!(
+        "(?:明治|大正|昭和|平成|令和)(?:元|[{n}]+)年(?:人事院規則[{n}]+(?:[―－\\-‐ー—][{n}]+){{1,2}}|[^\\s（）()「」『』、。，．,.]{{1,30}}?第[{n}]+号)"
+    ))
+    .unwrap()
+});
+
+/// 「昭和二十二年法律第六十七号」や「平成十年人事院規則一四―七」のような法令番号を探す
+fn find_law_nums(text: &str) -> Vec<LawIdMatch> {
+    let (joined, offsets) = remove_line_breaks(text);
+    let mut matches = Vec::new();
+    let mut position = 0;
+    while let Some(m) = LAW_NUM_RE.find_at(&joined, position) {
+        match LawId::candidates_from_law_num(m.as_str()) {
+            Ok(candidates) if !candidates.is_empty() => {
+                matches.push(LawIdMatch {
+                    range: offsets[m.start()]..offsets[m.end() - 1] + 1,
+                    style: MatchStyle::LawNum,
+                    law_id: match candidates.as_slice() {
+                        [law_id] => Some(*law_id),
+                        _ => None,
+                    },
+                    candidates,
+                    parenthesized: false,
+                });
+                position = m.end();
+            }
+            // 前の文章の「平成十年」などから始まっていることがあるため，一文字進めて探し直す
+            _ => {
+                position = m.start() + joined[m.start()..].chars().next().unwrap().len_utf8();
+            }
+        }
+    }
+    matches
+}
+
+#[test]
+fn check_find_law_ids() {
+    let text = "電波法（昭和二十五年法律第百三十一号）の法令IDは325AC0000000131，\
+                施行規則は３２５Ｍ５０００１０００００４である．";
+    let matches: Vec<LawIdMatch> = find_law_ids(text).collect();
+    assert_eq!(matches.len(), 3);
+    assert_eq!(matches[0].style, MatchStyle::LawNum);
+    assert_eq!(matches[0].as_str(text), "昭和二十五年法律第百三十一号");
+    assert!(matches[0].parenthesized);
+    assert_eq!(matches[0].law_id, None);
+    assert_eq!(
+        matches[0].candidates[0],
+        LawId::from_id_str("325AC0000000131").unwrap()
+    );
+    assert_eq!(matches[0].candidates.len(), 3);
+    assert_eq!(matches[1].style, MatchStyle::LawId);
+    assert_eq!(matches[1].as_str(text), "325AC0000000131");
+    assert!(!matches[1].parenthesized);
+    assert_eq!(matches[2].style, MatchStyle::FullWidthLawId);
+    assert_eq!(
+        matches[2].law_id,
+        Some(LawId::from_id_str("325M50001000004").unwrap())
+    );
+}
+
+#[test]
+fn check_find_law_nums() {
+    let text = "令和５年法律第６０号及び昭和二十五年郵政省令\n    第四号，\
+                平成十年人事院規則一四―七．平成十年に改正された（令和元年政令第一号）";
+    let matches: Vec<LawIdMatch> = find_law_ids(text).collect();
+    let found: Vec<(&str, String)> = matches
+        .iter()
+        .map(|m| (m.as_str(text), m.candidates[0].to_id_str()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            ("令和５年法律第６０号", String::from("505AC0000000060")),
+            (
+                "昭和二十五年郵政省令\n    第四号",
+                String::from("325M50001000004")
+            ),
+            ("平成十年人事院規則一四―七", String::from("410RJNJ14007000")),
+            ("令和元年政令第一号", String::from("501CO0000000001")),
+        ]
+    );
+    assert_eq!(matches[0].law_id, None);
+    assert!(matches[1..].iter().all(|m| m.law_id.is_some()));
+    assert!(matches[3].parenthesized);
+    assert_eq!(find_law_ids("ABCDEFGHIJKLMNO 322AC00000000670").count(), 0);
+}
@@ -0,0 +1,193 @@
+//! `serde`でのシリアライズの形式
+//!
+//! `LawId`，`LawType`，`Ministry`は既定では「325M50001000004」のような法令IDの文字列としてシリアライズし，
+//! デシリアライズのときは法令IDとして解析できるかを検査する．
+//! フィールドごとに形式を選ぶには，`#[serde(with = "...")]`に次のモジュールを指定する．
+//!
+//! - [`string`]：法令IDの文字列（既定と同じ）
+//! - [`structured`]：元号や種別などをそのまま入れ子にした形式
+//!
+//! ```
+//! # use japanese_law_id::*;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     id: LawId,
+//!     #[serde(with = "japanese_law_id::serde_format::structured")]
+//!     decoded: LawId,
+//! }
+//! ```
+
+use crate::*;
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+use std::marker::PhantomData;
+
+/// 文字列から解析する値の`Visitor`
+struct StrVisitor<T>(PhantomData<T>);
+
+impl<T: FromStr<Err = LawIdError>> Visitor<'_> for StrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a law ID string")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        s.parse().map_err(E::custom)
+    }
+}
+
+/// 文字列としてのシリアライズ・デシリアライズ
+///
+/// `FromStr`と`Display`で法令IDなどの文字列と相互に変換できる型に使える
+pub mod string {
+    use super::*;
+
+    pub fn serialize<T: fmt::Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T: FromStr<Err = LawIdError>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_str(StrVisitor(PhantomData))
+    }
+}
+
+/// 入れ子にした形式でシリアライズできる型
+pub trait Structured: Sized {
+    fn serialize_structured<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+    fn deserialize_structured<'de, D: Deserializer<'de>>(deserializer: D)
+    -> Result<Self, D::Error>;
+}
+
+/// `serde(remote = "Self")`で導出した入れ子の形式を`Structured`として使う
+///
+/// 入れ子の形式でも，法令IDの文字列にして解析し直すと同じ値に戻るものだけを受け付ける
+macro_rules! impl_structured {
+    ($($t:ty),*) => {
+        $(
+            impl Structured for $t {
+                fn serialize_structured<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    <$t>::serialize(self, serializer)
+                }
+
+                fn deserialize_structured<'de, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let value = <$t>::deserialize(deserializer)?;
+                    let id = value.to_string();
+                    match id.parse::<$t>() {
+                        Ok(parsed) if parsed == value => Ok(value),
+                        Ok(_) => Err(de::Error::custom(format!(
+                            "{id:?} does not parse back to the same value"
+                        ))),
+                        Err(e) => Err(de::Error::custom(format!("{id:?}: {e}"))),
+                    }
+                }
+            }
+
+            impl Serialize for $t {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    string::serialize(self, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $t {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    string::deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_structured!(LawId, LawType, Ministry);
+
+/// 元号や種別などをそのまま入れ子にした形式でのシリアライズ・デシリアライズ
+///
+/// `{"wareki":{"era":"Showa","year":25},"law_type":{"MinistryOrder":{...}}}`のようになる
+pub mod structured {
+    use super::*;
+
+    pub fn serialize<T: Structured, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize_structured(serializer)
+    }
+
+    pub fn deserialize<'de, T: Structured, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::deserialize_structured(deserializer)
+    }
+}
+
+#[test]
+fn check_serde_string() {
+    let law_id = LawId::from_id_str("325M50001000004").unwrap();
+    assert_eq!(
+        serde_json::to_string(&law_id).unwrap(),
+        r#""325M50001000004""#
+    );
+    assert_eq!(
+        serde_json::from_str::<LawId>(r#""325M50001000004""#).unwrap(),
+        law_id
+    );
+    assert_eq!(
+        serde_json::to_string(&law_id.law_type).unwrap(),
+        r#""M50001000004""#
+    );
+    let LawType::MinistryOrder { ministry, .. } = law_id.law_type else {
+        unreachable!()
+    };
+    assert_eq!(
+        serde_json::from_str::<Ministry>(r#""M50001000""#).unwrap(),
+        ministry
+    );
+    let e = serde_json::from_str::<LawId>(r#""325XX0000000004""#).unwrap_err();
+    assert!(e.to_string().contains("unknown law type at byte 3"));
+    assert!(serde_json::from_str::<LawId>("325").is_err());
+}
+
+#[test]
+fn check_serde_structured() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        id: LawId,
+        #[serde(with = "structured")]
+        decoded: LawId,
+        #[serde(with = "string")]
+        revision: LawRevisionId,
+    }
+    let law_id = LawId::from_id_str("322AC0000000067").unwrap();
+    let record = Record {
+        id: law_id,
+        decoded: law_id,
+        revision: LawRevisionId::from_id_str("322AC0000000067_20230614_505AC0000000053").unwrap(),
+    };
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(
+        json,
+        r#"{"id":"322AC0000000067","decoded":{"wareki":{"era":"Showa","year":22},"law_type":{"Act":{"rippou_type":"Kakuhou","num":67}}},"revision":"322AC0000000067_20230614_505AC0000000053"}"#
+    );
+    assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+
+    #[derive(Debug, Deserialize)]
+    struct Decoded(#[serde(with = "structured")] LawId);
+    let e = serde_json::from_str::<Decoded>(
+        r#"{"wareki":{"era":"Showa","year":250},"law_type":{"Act":{"rippou_type":"Kakuhou","num":123456}}}"#,
+    )
+    .unwrap_err();
+    assert!(e.to_string().contains("3250AC0000000123456"), "{e}");
+    let decoded = serde_json::from_str::<Decoded>(
+        r#"{"wareki":{"era":"Showa","year":22},"law_type":{"Act":{"rippou_type":"Kakuhou","num":67}}}"#,
+    )
+    .unwrap();
+    assert_eq!(decoded.0, law_id);
+}
@@ -0,0 +1,525 @@
+//! e-Gov法令検索，e-Gov法令API，日本法令索引のURLの生成と解析
+
+use crate::*;
+
+/// e-Gov法令検索のURLの先頭
+const EGOV_BASE: &str = "https://laws.e-gov.go.jp";
+
+/// e-Gov法令API Version 2のURLの先頭
+const EGOV_API_BASE: &str = "https://laws.e-gov.go.jp/api/2";
+
+/// 日本法令索引の法令の詳細ページのURLの先頭
+const NDL_DETAIL_BASE: &str = "https://hourei.ndl.go.jp/#/detail?lawId=";
+
+/// 法令を指すURL
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum LawUrl {
+    /// e-Gov法令検索の法令のページ（`https://laws.e-gov.go.jp/law/322AC0000000067`など）
+    ///
+    /// 旧サイトの`https://elaws.e-gov.go.jp/document?lawid=322AC0000000067`も解析できる
+    Egov {
+        target: LawTarget,
+        /// 「Mp-At_9」のような条文の位置
+        anchor: Option<String>,
+    },
+    /// e-Gov法令API Version 2のエンドポイント
+    EgovApi {
+        endpoint: EgovApiEndpoint,
+        /// 改正履歴の版は`LawData`と`LawFile`のみ指定できる．
+        /// `Laws`と`LawRevisions`では版の法令IDだけをURLに含める
+        target: LawTarget,
+    },
+    /// 国立国会図書館「日本法令索引」の法令の詳細ページ
+    ///
+    /// 日本法令索引の法令IDは法令IDとは別の番号のため，法令IDには変換できない
+    Ndl {
+        /// 「0000012345」のような日本法令索引の法令ID
+        ndl_law_id: String,
+    },
+}
+
+/// URLが指す法令，または法令の改正履歴の版
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum LawTarget {
+    /// 法令（最新の版）
+    Law(LawId),
+    /// 法令の改正履歴の版
+    Revision(LawRevisionId),
+}
+
+impl LawTarget {
+    /// 法令の法令ID
+    pub fn law_id(&self) -> &LawId {
+        match self {
+            Self::Law(law_id) => law_id,
+            Self::Revision(revision) => &revision.law_id,
+        }
+    }
+
+    /// 改正履歴の版
+    pub fn revision(&self) -> Option<&LawRevisionId> {
+        match self {
+            Self::Law(_) => None,
+            Self::Revision(revision) => Some(revision),
+        }
+    }
+
+    /// URL中の「322AC0000000067」や「322AC0000000067/20230614_505AC0000000053」のような部分
+    fn to_path(&self, separator: char) -> String {
+        match self {
+            Self::Law(law_id) => law_id.to_id_str(),
+            Self::Revision(revision) => {
+                format!("{}{separator}{}", revision.law_id, revision.suffix())
+            }
+        }
+    }
+}
+
+impl From<LawId> for LawTarget {
+    fn from(law_id: LawId) -> Self {
+        Self::Law(law_id)
+    }
+}
+
+impl From<LawRevisionId> for LawTarget {
+    fn from(revision: LawRevisionId) -> Self {
+        Self::Revision(revision)
+    }
+}
+
+/// e-Gov法令API Version 2のエンドポイント
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum EgovApiEndpoint {
+    /// 法令一覧（`/laws?law_id=`）
+    Laws,
+    /// 法令本文（`/law_data/`）
+    LawData,
+    /// 改正履歴の一覧（`/law_revisions/`）
+    LawRevisions,
+    /// 法令本文のファイル（`/law_file/`）
+    LawFile(LawFileType),
+}
+
+/// e-Gov法令APIで取得できるファイルの形式
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum LawFileType {
+    Xml,
+    Json,
+    Html,
+    Rtf,
+    Docx,
+}
+
+impl LawFileType {
+    const ALL: [Self; 5] = [Self::Xml, Self::Json, Self::Html, Self::Rtf, Self::Docx];
+
+    /// URL中の「xml」などの表記
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Xml => "xml",
+            Self::Json => "json",
+            Self::Html => "html",
+            Self::Rtf => "rtf",
+            Self::Docx => "docx",
+        }
+    }
+}
+
+impl LawUrl {
+    /// URLを生成する
+    pub fn to_url(&self) -> String {
+        match self {
+            Self::Egov { target, anchor } => {
+                let mut url = format!("{EGOV_BASE}/law/{}", target.to_path('/'));
+                if let Some(anchor) = anchor {
+                    url.push('#');
+                    url.push_str(anchor);
+                }
+                url
+            }
+            Self::EgovApi { endpoint, target } => {
+                let id = target.to_path('_');
+                let law_id = target.law_id();
+                match endpoint {
+                    EgovApiEndpoint::Laws => format!("{EGOV_API_BASE}/laws?law_id={law_id}"),
+                    EgovApiEndpoint::LawData => format!("{EGOV_API_BASE}/law_data/{id}"),
+                    EgovApiEndpoint::LawRevisions => {
+                        format!("{EGOV_API_BASE}/law_revisions/{law_id}")
+                    }
+                    EgovApiEndpoint::LawFile(file_type) => {
+                        format!("{EGOV_API_BASE}/law_file/{}/{id}", file_type.as_str())
+                    }
+                }
+            }
+            Self::Ndl { ndl_law_id } => format!("{NDL_DETAIL_BASE}{ndl_law_id}"),
+        }
+    }
+
+    /// URLを解析する
+    ///
+    /// スキームは`https`と`http`を受け付ける．
+    /// ホスト名が一致しないものや，パスの形式が異なるものは`LawIdError::InvalidUrl`になる
+    pub fn parse(url: &str) -> Result<Self, LawIdError> {
+        let host_start = if url.starts_with("https://") {
+            "https://".len()
+        } else if url.starts_with("http://") {
+            "http://".len()
+        } else {
+            return Err(LawIdError::InvalidUrl { position: 0 });
+        };
+        let rest = &url[host_start..];
+        let host_end = host_start + rest.find('/').unwrap_or(rest.len());
+        let path_start = host_end;
+        match &url[host_start..host_end] {
+            "laws.e-gov.go.jp" => parse_egov_path(url, path_start),
+            "elaws.e-gov.go.jp" => parse_elaws_path(url, path_start),
+            "hourei.ndl.go.jp" => parse_ndl_path(url, path_start),
+            _ => Err(LawIdError::InvalidUrl {
+                position: host_start,
+            }),
+        }
+    }
+
+    /// URLが指す法令の法令ID
+    ///
+    /// 日本法令索引のURLは`None`
+    pub fn law_id(&self) -> Option<&LawId> {
+        self.target().map(LawTarget::law_id)
+    }
+
+    /// URLが指す法令や改正履歴の版
+    ///
+    /// 日本法令索引のURLは`None`
+    pub fn target(&self) -> Option<&LawTarget> {
+        match self {
+            Self::Egov { target, .. } | Self::EgovApi { target, .. } => Some(target),
+            Self::Ndl { .. } => None,
+        }
+    }
+}
+
+/// `/law/{id}/{revision}#{anchor}`を解析する
+fn parse_egov_path(url: &str, start: usize) -> Result<LawUrl, LawIdError> {
+    let (path, anchor) = match url[start..].find('#') {
+        Some(i) => (&url[start..start + i], Some(&url[start + i + 1..])),
+        None => (&url[start..], None),
+    };
+    if let Some(rest) = path.strip_prefix("/api/2/") {
+        if anchor.is_some() {
+            return Err(LawIdError::InvalidUrl {
+                position: start + path.len(),
+            });
+        }
+        return parse_egov_api_path(start + "/api/2/".len(), rest);
+    }
+    let id_start = start + "/law/".len();
+    let rest = path
+        .strip_prefix("/law/")
+        .ok_or(LawIdError::InvalidUrl { position: start })?;
+    let mut segments = rest.strip_suffix('/').unwrap_or(rest).split('/');
+    let id = segments.next().unwrap_or_default();
+    let law_id = parse_law_id_segment(id, id_start)?;
+    let target = match segments.next() {
+        Some(revision) => parse_revision(law_id, revision, id_start + id.len() + 1)?.into(),
+        None => LawTarget::Law(law_id),
+    };
+    if segments.next().is_some() {
+        return Err(LawIdError::InvalidUrl {
+            position: start + path.len(),
+        });
+    }
+    let anchor = match anchor {
+        Some("") => {
+            return Err(LawIdError::InvalidUrl {
+                position: url.len(),
+            });
+        }
+        anchor => anchor.map(String::from),
+    };
+    Ok(LawUrl::Egov { target, anchor })
+}
+
+/// `/api/2/`より後の部分を解析する
+fn parse_egov_api_path(start: usize, path: &str) -> Result<LawUrl, LawIdError> {
+    let (path, query) = match path.find('?') {
+        Some(i) => (&path[..i], Some((start + i + 1, &path[i + 1..]))),
+        None => (path, None),
+    };
+    let invalid = |position| LawIdError::InvalidUrl { position };
+    let (endpoint, id_start) = if path == "laws" {
+        let (query_start, query) = query.ok_or(invalid(start + path.len()))?;
+        let mut pos = query_start;
+        let mut id = None;
+        for param in query.split('&') {
+            if let Some(value) = param.strip_prefix("law_id=") {
+                id = Some((value, pos + "law_id=".len()));
+            }
+            pos += param.len() + 1;
+        }
+        let (id, id_start) = id.ok_or(invalid(query_start))?;
+        let law_id = parse_law_id_segment(id, id_start)?;
+        return Ok(LawUrl::EgovApi {
+            endpoint: EgovApiEndpoint::Laws,
+            target: LawTarget::Law(law_id),
+        });
+    } else if let Some(rest) = path.strip_prefix("law_data/") {
+        (EgovApiEndpoint::LawData, path.len() - rest.len())
+    } else if let Some(rest) = path.strip_prefix("law_revisions/") {
+        (EgovApiEndpoint::LawRevisions, path.len() - rest.len())
+    } else if let Some(rest) = path.strip_prefix("law_file/") {
+        let (file_type, rest) = rest.split_once('/').ok_or(invalid(start + path.len()))?;
+        let file_type = LawFileType::ALL
+            .into_iter()
+            .find(|t| t.as_str() == file_type)
+            .ok_or(invalid(start + "law_file/".len()))?;
+        (EgovApiEndpoint::LawFile(file_type), path.len() - rest.len())
+    } else {
+        return Err(invalid(start));
+    };
+    let id = &path[id_start..];
+    let id_start = start + id_start;
+    let (id, revision) = match id.split_once('_') {
+        Some((id, revision)) if endpoint != EgovApiEndpoint::LawRevisions => {
+            (id, Some((revision, id_start + id.len() + 1)))
+        }
+        _ => (id, None),
+    };
+    let law_id = parse_law_id_segment(id, id_start)?;
+    let target = match revision {
+        Some((revision, start)) => parse_revision(law_id, revision, start)?.into(),
+        None => LawTarget::Law(law_id),
+    };
+    Ok(LawUrl::EgovApi { endpoint, target })
+}
+
+/// 旧サイトの`/document?lawid={id}`を解析する
+fn parse_elaws_path(url: &str, start: usize) -> Result<LawUrl, LawIdError> {
+    let query = url[start..]
+        .strip_prefix("/document?")
+        .ok_or(LawIdError::InvalidUrl { position: start })?;
+    let mut pos = start + "/document?".len();
+    for param in query.split('&') {
+        if let Some(id) = param.strip_prefix("lawid=") {
+            let law_id = parse_law_id_segment(id, pos + "lawid=".len())?;
+            return Ok(LawUrl::Egov {
+                target: LawTarget::Law(law_id),
+                anchor: None,
+            });
+        }
+        pos += param.len() + 1;
+    }
+    Err(LawIdError::InvalidUrl {
+        position: start + "/document?".len(),
+    })
+}
+
+/// `/#/detail?lawId={id}`を解析する
+fn parse_ndl_path(url: &str, start: usize) -> Result<LawUrl, LawIdError> {
+    let prefix = "/#/detail?lawId=";
+    let id = url[start..]
+        .strip_prefix(prefix)
+        .ok_or(LawIdError::InvalidUrl { position: start })?;
+    let id_start = start + prefix.len();
+    let id = &id[..id.find('&').unwrap_or(id.len())];
+    if id.is_empty() {
+        return Err(LawIdError::InvalidUrl { position: id_start });
+    }
+    if let Some(i) = id.bytes().position(|b| !b.is_ascii_digit()) {
+        return Err(LawIdError::InvalidDigit {
+            position: id_start + i,
+        });
+    }
+    Ok(LawUrl::Ndl {
+        ndl_law_id: id.to_string(),
+    })
+}
+
+/// URL中の15文字の法令IDを解析する
+fn parse_law_id_segment(s: &str, start: usize) -> Result<LawId, LawIdError> {
+    LawId::from_id_str(s).map_err(|e| e.offset(start))
+}
+
+/// URL中の「20230614_505AC0000000053」のような改正履歴の版を解析する
+///
+/// 制定時の版では改正法令の法令IDが「000000000000000」になる
+fn parse_revision(law_id: LawId, s: &str, start: usize) -> Result<LawRevisionId, LawIdError> {
+    LawRevisionId::with_suffix(law_id, s).map_err(|e| match e {
+        LawIdError::InvalidRevisionId { position } => LawIdError::InvalidUrl {
+            position: start + position,
+        },
+        e => e.offset(start),
+    })
+}
+
+impl fmt::Display for LawUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_url())
+    }
+}
+
+impl FromStr for LawUrl {
+    type Err = LawIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl_str_conversions!(LawUrl);
+
+impl LawId {
+    /// e-Gov法令検索，e-Gov法令APIのURLから法令IDを取り出す
+    ///
+    /// 日本法令索引のURLは解析できるが，法令IDを含まないため`LawIdError::NoLawIdInUrl`になる
+    pub fn from_url(url: &str) -> Result<Self, LawIdError> {
+        LawUrl::parse(url)?
+            .law_id()
+            .copied()
+            .ok_or(LawIdError::NoLawIdInUrl { position: 0 })
+    }
+
+    /// e-Gov法令検索の法令のページのURL
+    pub fn egov_url(&self) -> String {
+        LawUrl::Egov {
+            target: LawTarget::Law(*self),
+            anchor: None,
+        }
+        .to_url()
+    }
+
+    /// e-Gov法令API Version 2のエンドポイントのURL
+    pub fn egov_api_url(&self, endpoint: EgovApiEndpoint) -> String {
+        LawUrl::EgovApi {
+            endpoint,
+            target: LawTarget::Law(*self),
+        }
+        .to_url()
+    }
+}
+
+#[test]
+fn check_egov_url() {
+    let law_id = LawId::from_id_str("322AC0000000067").unwrap();
+    assert_eq!(
+        law_id.egov_url(),
+        "https://laws.e-gov.go.jp/law/322AC0000000067"
+    );
+    let url = "https://laws.e-gov.go.jp/law/322AC0000000067/20230614_505AC0000000053#Mp-At_9";
+    let revision = LawRevisionId::from_id_str("322AC0000000067_20230614_505AC0000000053").unwrap();
+    let law_url = LawUrl::Egov {
+        target: LawTarget::Revision(revision.clone()),
+        anchor: Some(String::from("Mp-At_9")),
+    };
+    assert_eq!(LawUrl::parse(url), Ok(law_url.clone()));
+    assert_eq!(law_url.to_url(), url);
+    assert_eq!(law_url.law_id(), Some(&law_id));
+    let api_url = LawUrl::EgovApi {
+        endpoint: EgovApiEndpoint::LawData,
+        target: LawTarget::Revision(revision.clone()),
+    };
+    assert_eq!(
+        api_url.to_url(),
+        "https://laws.e-gov.go.jp/api/2/law_data/322AC0000000067_20230614_505AC0000000053"
+    );
+    assert_eq!(LawUrl::parse(&api_url.to_url()), Ok(api_url));
+    assert_eq!(
+        LawUrl::EgovApi {
+            endpoint: EgovApiEndpoint::LawRevisions,
+            target: LawTarget::Revision(revision),
+        }
+        .to_url(),
+        "https://laws.e-gov.go.jp/api/2/law_revisions/322AC0000000067"
+    );
+    for url in [
+        "https://laws.e-gov.go.jp/law/322AC0000000067",
+        "http://laws.e-gov.go.jp/law/322AC0000000067/",
+        "https://laws.e-gov.go.jp/law/322AC0000000067#Mp-At_1",
+        "https://elaws.e-gov.go.jp/document?lawid=322AC0000000067",
+        "https://laws.e-gov.go.jp/api/2/laws?law_id=322AC0000000067",
+        "https://laws.e-gov.go.jp/api/2/law_data/322AC0000000067_19470503_000000000000000",
+        "https://laws.e-gov.go.jp/api/2/law_revisions/322AC0000000067",
+        "https://laws.e-gov.go.jp/api/2/law_file/xml/322AC0000000067",
+    ] {
+        assert_eq!(LawId::from_url(url), Ok(law_id), "{url}");
+    }
+    for endpoint in [
+        EgovApiEndpoint::Laws,
+        EgovApiEndpoint::LawData,
+        EgovApiEndpoint::LawRevisions,
+        EgovApiEndpoint::LawFile(LawFileType::Docx),
+    ] {
+        let url = law_id.egov_api_url(endpoint);
+        assert_eq!(
+            LawUrl::parse(&url),
+            Ok(LawUrl::EgovApi {
+                endpoint,
+                target: LawTarget::Law(law_id)
+            })
+        );
+    }
+}
+
+#[test]
+fn check_ndl_url() {
+    let url = "https://hourei.ndl.go.jp/#/detail?lawId=0000012345";
+    let law_url = LawUrl::Ndl {
+        ndl_law_id: String::from("0000012345"),
+    };
+    assert_eq!(LawUrl::parse(url), Ok(law_url.clone()));
+    assert_eq!(law_url.to_string(), url);
+    assert_eq!(law_url.law_id(), None);
+    assert_eq!(
+        LawId::from_url(url),
+        Err(LawIdError::NoLawIdInUrl { position: 0 })
+    );
+}
+
+#[test]
+fn check_url_lookalikes() {
+    assert_eq!(
+        LawId::from_url("https://laws.e-gov.go.jp.example.com/law/322AC0000000067"),
+        Err(LawIdError::InvalidUrl { position: 8 })
+    );
+    assert_eq!(
+        LawId::from_url("https://laws-e-gov.go.jp/law/322AC0000000067"),
+        Err(LawIdError::InvalidUrl { position: 8 })
+    );
+    assert_eq!(
+        LawId::from_url("ftp://laws.e-gov.go.jp/law/322AC0000000067"),
+        Err(LawIdError::InvalidUrl { position: 0 })
+    );
+    assert_eq!(
+        LawId::from_url("https://laws.e-gov.go.jp/laws/322AC0000000067"),
+        Err(LawIdError::InvalidUrl { position: 24 })
+    );
+    assert_eq!(
+        LawId::from_url("https://laws.e-gov.go.jp/law/322AC00000000670"),
+        Err(LawIdError::InvalidLength {
+            position: 44,
+            expected: 15,
+            found: 16
+        })
+    );
+    assert_eq!(
+        LawId::from_url("https://laws.e-gov.go.jp/law/322AC0000000067/20230230_505AC0000000053"),
+        Err(LawIdError::InvalidDate { position: 45 })
+    );
+    assert!(LawId::from_url("https://laws.e-gov.go.jp/law/322AC0000000067/x").is_err());
+    assert!(
+        LawId::from_url("https://laws.e-gov.go.jp/api/2/law_file/pdf/322AC0000000067").is_err()
+    );
+    assert!(LawId::from_url("https://hourei.ndl.go.jp/#/detail?lawId=12a").is_err());
+    assert_eq!(
+        LawUrl::parse("https://hourei.ndl.go.jp/#/detail?lawId=99999999999999999999999999"),
+        Ok(LawUrl::Ndl {
+            ndl_law_id: "99999999999999999999999999".to_string()
+        })
+    );
+    assert!(
+        LawId::from_url("https://hourei.ndl.go.jp/#/detail?lawId=99999999999999999999999999")
+            .is_err()
+    );
+}
@@ -0,0 +1,284 @@
+//! 構文上は正しい法令IDが実際にありうるものかどうかの検査
+
+use crate::*;
+
+/// `LawId::validate`で見つかった問題
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Diagnostic {
+    /// 元号の範囲外の年（「昭和99年」，「令和0年」など）
+    EraYearOutOfRange { era: Era, year: usize },
+    /// 府省令の区分（M1〜M6）が年と合わない
+    MinistryPeriodMismatch {
+        /// 法令IDでの区分
+        period: usize,
+        /// 年から考えられる区分
+        expected: Vec<usize>,
+    },
+    /// 府省令に担当する府省が一つも無い
+    EmptyMinistry,
+    /// その年には存在しない法令の種別（令和の太政官布告，昭和22年より後の勅令など）
+    LawTypeNotInEra { wareki: Wareki },
+    /// 法令番号などの連番が0
+    ZeroSerialNumber,
+    /// 存在しない月日
+    InvalidDate { month: usize, day: usize },
+    /// 法令IDの固定幅の欄に収まらない値（令和100年，第1000号など）
+    ValueTooLarge {
+        /// 欄の名前（`"year"`，`"num"`など）
+        field: &'static str,
+        value: usize,
+        /// 欄に収まる最大の値
+        max: usize,
+    },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Diagnostic::*;
+        match self {
+            EraYearOutOfRange { era, year } => write!(f, "year {year} is out of range for {era}"),
+            MinistryPeriodMismatch { period, expected } => {
+                let expected: Vec<String> = expected.iter().map(|p| format!("M{p}")).collect();
+                write!(
+                    f,
+                    "ministry period M{period} does not match the year (expected {})",
+                    expected.join(" or ")
+                )
+            }
+            EmptyMinistry => write!(f, "no ministry is set"),
+            LawTypeNotInEra { wareki } => write!(f, "law type did not exist in {wareki}"),
+            ZeroSerialNumber => write!(f, "serial number is zero"),
+            InvalidDate { month, day } => write!(f, "invalid date {month}/{day}"),
+            ValueTooLarge { field, value, max } => {
+                write!(f, "{field} {value} does not fit in the law ID (max {max})")
+            }
+        }
+    }
+}
+
+impl LawType {
+    /// 法令IDの各欄の名前，値，欄に収まる最大の値
+    pub(crate) fn id_fields(&self) -> Vec<(&'static str, usize, usize)> {
+        use LawType::*;
+        match self {
+            Constitution => vec![],
+            Act { num, .. }
+            | CabinetOrder { num, .. }
+            | ImperialOrder { num, .. }
+            | DajokanFukoku { num, .. }
+            | DajokanTasshi { num, .. }
+            | DajokanHutatsu { num, .. }
+            | MinistryOrder { num, .. }
+            | Regulation { num, .. } => vec![("num", *num, MAX_3_DIGITS)],
+            Jinjin {
+                kind,
+                kind_serial_number,
+                amendment_serial_number,
+            } => vec![
+                ("kind", *kind, MAX_2_DIGITS),
+                ("kind_serial_number", *kind_serial_number, MAX_3_DIGITS),
+                (
+                    "amendment_serial_number",
+                    *amendment_serial_number,
+                    MAX_3_DIGITS,
+                ),
+            ],
+            PrimeMinisterDecision { month, day, num } => vec![
+                ("month", *month, MAX_2_DIGITS),
+                ("day", *day, MAX_2_DIGITS),
+                ("num", *num, MAX_4_DIGITS),
+            ],
+        }
+    }
+}
+
+/// 法令の種別が存在した西暦年の最初と最後
+///
+/// 現在も存在する種別の最後の年は`None`
+fn law_type_years(law_type: &LawType) -> Option<(usize, Option<usize>)> {
+    use LawType::*;
+    match law_type {
+        // 明治18年の内閣制度の創設まで
+        DajokanFukoku { .. } | DajokanTasshi { .. } | DajokanHutatsu { .. } => {
+            Some((1868, Some(1885)))
+        }
+        // 明治19年の公文式から日本国憲法の施行まで
+        ImperialOrder { .. } => Some((1886, Some(1947))),
+        Act { .. } => Some((1886, None)),
+        CabinetOrder { .. } => Some((1947, None)),
+        // 人事院は昭和23年に設置された
+        Jinjin { .. } => Some((1948, None)),
+        Constitution | MinistryOrder { .. } | Regulation { .. } | PrimeMinisterDecision { .. } => {
+            None
+        }
+    }
+}
+
+impl LawId {
+    /// 構文上は正しくても実際にはありえない箇所を検査する
+    ///
+    /// 問題が無ければ空の`Vec`を返す
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        use LawType::*;
+        let mut diagnostics = Vec::new();
+        let era = self.wareki.get_era();
+        let year = self.wareki.get_year();
+        if year == 0 || era.last_year().is_some_and(|last| last < year) {
+            diagnostics.push(Diagnostic::EraYearOutOfRange { era, year });
+        }
+        let fields = std::iter::once(("year", year, MAX_2_DIGITS))
+            .chain(self.law_type.id_fields())
+            .filter(|(_, value, max)| value > max)
+            .map(|(field, value, max)| Diagnostic::ValueTooLarge { field, value, max });
+        diagnostics.extend(fields);
+        if let Some((first, last)) = law_type_years(&self.law_type) {
+            let year = self.wareki.to_ad();
+            if year < first || last.is_some_and(|last| last < year) {
+                diagnostics.push(Diagnostic::LawTypeNotInEra {
+                    wareki: self.wareki,
+                });
+            }
+        }
+        let zero_serial = match &self.law_type {
+            Constitution => false,
+            Act { num, .. }
+            | CabinetOrder { num, .. }
+            | ImperialOrder { num, .. }
+            | DajokanFukoku { num, .. }
+            | DajokanTasshi { num, .. }
+            | DajokanHutatsu { num, .. }
+            | MinistryOrder { num, .. }
+            | Regulation { num, .. }
+            | PrimeMinisterDecision { num, .. } => *num == 0,
+            Jinjin {
+                kind,
+                kind_serial_number,
+                ..
+            } => *kind == 0 || *kind_serial_number == 0,
+        };
+        if zero_serial {
+            diagnostics.push(Diagnostic::ZeroSerialNumber);
+        }
+        match &self.law_type {
+            MinistryOrder { ministry, .. } => {
+                if ministry.is_empty() {
+                    diagnostics.push(Diagnostic::EmptyMinistry);
+                }
+                let expected = Ministry::periods_of_wareki(self.wareki);
+                if !expected.contains(&ministry.period()) {
+                    diagnostics.push(Diagnostic::MinistryPeriodMismatch {
+                        period: ministry.period(),
+                        expected,
+                    });
+                }
+            }
+            PrimeMinisterDecision { month, day, .. }
+                if *day == 0 || days_in_month(self.wareki.to_ad(), *month) < *day =>
+            {
+                diagnostics.push(Diagnostic::InvalidDate {
+                    month: *month,
+                    day: *day,
+                });
+            }
+            _ => (),
+        }
+        diagnostics
+    }
+}
+
+#[test]
+fn check_validate() {
+    let validate = |s: &str| LawId::from_id_str(s).unwrap().validate();
+    assert_eq!(validate("325M50001000004"), []);
+    assert_eq!(validate("505M60001024060"), []);
+    assert_eq!(validate("345AC0000000089"), []);
+    assert_eq!(
+        validate("399AC0000000001"),
+        [Diagnostic::EraYearOutOfRange {
+            era: Era::Showa,
+            year: 99
+        }]
+    );
+    assert_eq!(
+        validate("500AC0000000001"),
+        [Diagnostic::EraYearOutOfRange {
+            era: Era::Reiwa,
+            year: 0
+        }]
+    );
+    assert_eq!(validate("505M60000000001"), [Diagnostic::EmptyMinistry]);
+    assert_eq!(
+        validate("110M60000004001"),
+        [Diagnostic::MinistryPeriodMismatch {
+            period: 6,
+            expected: vec![1]
+        }]
+    );
+    assert_eq!(
+        validate("501DF0000000001"),
+        [Diagnostic::LawTypeNotInEra {
+            wareki: Wareki::new(Era::Reiwa, 1)
+        }]
+    );
+    assert_eq!(
+        validate("325IO0000000001"),
+        [Diagnostic::LawTypeNotInEra {
+            wareki: Wareki::new(Era::Showa, 25)
+        }]
+    );
+    assert_eq!(validate("345CO0000000000"), [Diagnostic::ZeroSerialNumber]);
+    assert_eq!(validate("505RJNJ00001000"), [Diagnostic::ZeroSerialNumber]);
+    assert_eq!(validate("505M60000400000"), [Diagnostic::ZeroSerialNumber]);
+    assert_eq!(
+        validate("410RPMD13010001"),
+        [Diagnostic::InvalidDate { month: 13, day: 1 }]
+    );
+    assert_eq!(
+        validate("410RPMD02290001"),
+        [Diagnostic::InvalidDate { month: 2, day: 29 }]
+    );
+    assert_eq!(validate("412RPMD02290001"), []);
+}
+
+#[test]
+fn check_validate_value_too_large() {
+    let law_id = LawId {
+        wareki: Wareki::new(Era::Reiwa, 5),
+        law_type: LawType::CabinetOrder {
+            efficacy: LawEfficacy::CabinetOrder,
+            num: 1000,
+        },
+    };
+    // 番号の欄があふれて"505CO00000001000"になる
+    assert_eq!(
+        law_id.validate(),
+        [Diagnostic::ValueTooLarge {
+            field: "num",
+            value: 1000,
+            max: 999
+        }]
+    );
+    let law_id = LawId {
+        wareki: Wareki::new(Era::Reiwa, 100),
+        law_type: LawType::Jinjin {
+            kind: 100,
+            kind_serial_number: 1,
+            amendment_serial_number: 0,
+        },
+    };
+    assert_eq!(
+        law_id.validate(),
+        [
+            Diagnostic::ValueTooLarge {
+                field: "year",
+                value: 100,
+                max: 99
+            },
+            Diagnostic::ValueTooLarge {
+                field: "kind",
+                value: 100,
+                max: 99
+            }
+        ]
+    );
+}
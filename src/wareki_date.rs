@@ -0,0 +1,368 @@
+//! 「令和5年6月20日」のような和暦の年月日
+
+use crate::number::{format_number, parse_number};
+use crate::*;
+
+/// 和暦の年月日（令和5年6月20日など）
+///
+/// 明治5年までは旧暦（天保暦）の月日で，閏月も表せる．
+/// 元号，年，月（同じ月では閏月が後），日の順に比較する
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "WarekiDateFields")
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WarekiDate {
+    wareki: Wareki,
+    month: usize,
+    leap_month: bool,
+    day: usize,
+}
+
+/// デシリアライズした検査前の`WarekiDate`
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct WarekiDateFields {
+    wareki: Wareki,
+    month: usize,
+    leap_month: bool,
+    day: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<WarekiDateFields> for WarekiDate {
+    type Error = LawIdError;
+    fn try_from(fields: WarekiDateFields) -> Result<Self, Self::Error> {
+        Self::with_leap_month(
+            fields.wareki.get_era(),
+            fields.wareki.get_year(),
+            fields.month,
+            fields.leap_month,
+            fields.day,
+        )
+    }
+}
+
+/// 和暦の年より後の「六月二十日」，「閏十月五日」などの月日
+static MONTH_DAY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        "^(?<leap>閏)?(?<month>[0-9０-９〇一二三四五六七八九十]+)月(?<day>[0-9０-９〇一二三四五六七八九十]+)日",
+    )
+    .unwrap()
+});
+
+impl WarekiDate {
+    /// 元号と年月日から生成する
+    ///
+    /// 存在しない日付や，元号の期間外の日付（昭和64年1月8日など）は`LawIdError::InvalidDate`になる．
+    /// 明治5年までは旧暦の月日として扱う
+    pub fn new(era: Era, year: usize, month: usize, day: usize) -> Result<Self, LawIdError> {
+        Self::with_leap_month(era, year, month, false, day)
+    }
+
+    /// 「明治三年閏十月五日」のような旧暦の閏月の日付を生成する
+    pub fn new_leap_month(
+        era: Era,
+        year: usize,
+        month: usize,
+        day: usize,
+    ) -> Result<Self, LawIdError> {
+        Self::with_leap_month(era, year, month, true, day)
+    }
+
+    fn with_leap_month(
+        era: Era,
+        year: usize,
+        month: usize,
+        leap_month: bool,
+        day: usize,
+    ) -> Result<Self, LawIdError> {
+        let wareki = Wareki::new(era, year);
+        let valid = if kyureki::is_lunisolar(era, year) {
+            kyureki::to_gregorian(year, month, leap_month, day).is_some()
+        } else {
+            let ad_year = wareki.to_ad();
+            year != 0
+                && !leap_month
+                && Date::new_ad(ad_year, month, day).is_ok_and(|date| era.contains(date))
+        };
+        if !valid {
+            return Err(LawIdError::InvalidDate { position: 0 });
+        }
+        Ok(Self {
+            wareki,
+            month,
+            leap_month,
+            day,
+        })
+    }
+
+    /// 西暦の日付から生成する
+    ///
+    /// 明治より前の日付や存在しない日付は`LawIdError::InvalidDate`になる
+    pub fn from_date(date: Date) -> Result<Self, LawIdError> {
+        if date < kyureki::GREGORIAN_START {
+            let (year, month, leap_month, day) =
+                kyureki::from_gregorian(date).ok_or(LawIdError::InvalidDate { position: 0 })?;
+            return Ok(Self {
+                wareki: Wareki::new(Era::Meiji, year),
+                month,
+                leap_month,
+                day,
+            });
+        }
+        let wareki = date.gen_wareki_year()?;
+        Self::new(
+            wareki.get_era(),
+            wareki.get_year(),
+            date.get_month(),
+            date.get_day(),
+        )
+    }
+
+    /// 西暦の日付に変換する
+    pub fn to_date(self) -> Date {
+        let (era, year) = (self.wareki.get_era(), self.wareki.get_year());
+        if kyureki::is_lunisolar(era, year) {
+            // 生成時に旧暦の表にある日付であることを確認している
+            kyureki::to_gregorian(year, self.month, self.leap_month, self.day).unwrap()
+        } else {
+            Date {
+                year: self.wareki.to_ad(),
+                month: self.month,
+                day: self.day,
+            }
+        }
+    }
+
+    /// 「令和五年六月二十日」，「令和5年6月20日」，「令和５年６月２０日」，「明治三年閏十月五日」などのテキストから生成
+    pub fn from_text(text: &str) -> Result<Self, LawIdError> {
+        Self::parse_text(text).map(|(date, _)| date)
+    }
+
+    /// テキスト中の和暦の年月日を解析し，年月日とそれが現れた範囲を返す
+    fn parse_text(text: &str) -> Result<(Self, std::ops::Range<usize>), LawIdError> {
+        let (wareki, range) = Wareki::parse_text(text)?;
+        let rest_start = range.end;
+        let caps = MONTH_DAY_RE
+            .captures(&text[rest_start..])
+            .ok_or(LawIdError::InvalidDate {
+                position: rest_start,
+            })?;
+        let month_m = caps.name("month").unwrap();
+        let month = parse_number(month_m.as_str()).ok_or(LawIdError::InvalidDate {
+            position: rest_start + month_m.start(),
+        })?;
+        let day_m = caps.name("day").unwrap();
+        let day = parse_number(day_m.as_str()).ok_or(LawIdError::InvalidDate {
+            position: rest_start + day_m.start(),
+        })?;
+        let leap_month = caps.name("leap").is_some();
+        let date =
+            Self::with_leap_month(wareki.get_era(), wareki.get_year(), month, leap_month, day)
+                .map_err(|e| e.offset(range.start))?;
+        Ok((date, range.start..rest_start + caps.get(0).unwrap().end()))
+    }
+
+    /// 数字の表記と元年を使うかどうかを指定して「令和五年六月二十日」のような文字列を生成する
+    pub fn to_text_with(&self, numeral: Numeral, gannen: bool) -> String {
+        let year = self.wareki.get_year();
+        let year_s = if gannen && year == 1 {
+            String::from("元")
+        } else {
+            format_number(year, numeral)
+        };
+        format!(
+            "{}{year_s}年{}{}月{}日",
+            self.wareki.get_era(),
+            self.leap_str(),
+            format_number(self.month, numeral),
+            format_number(self.day, numeral)
+        )
+    }
+
+    fn leap_str(&self) -> &'static str {
+        if self.leap_month { "閏" } else { "" }
+    }
+
+    pub fn get_wareki(&self) -> Wareki {
+        self.wareki
+    }
+
+    pub fn get_month(&self) -> usize {
+        self.month
+    }
+
+    /// 旧暦の閏月かどうか
+    pub fn is_leap_month(&self) -> bool {
+        self.leap_month
+    }
+
+    pub fn get_day(&self) -> usize {
+        self.day
+    }
+}
+
+impl From<WarekiDate> for Date {
+    fn from(date: WarekiDate) -> Self {
+        date.to_date()
+    }
+}
+
+impl TryFrom<Date> for WarekiDate {
+    type Error = LawIdError;
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        Self::from_date(date)
+    }
+}
+
+/// 「令和5年6月20日」，「令和元年5月1日」の形式で出力する
+impl fmt::Display for WarekiDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}月{}日",
+            self.wareki,
+            self.leap_str(),
+            self.month,
+            self.day
+        )
+    }
+}
+
+/// 文字列全体が「令和5年6月20日」などの和暦の年月日の表記である必要がある
+impl FromStr for WarekiDate {
+    type Err = LawIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, range) = Self::parse_text(s)?;
+        if range.start != 0 {
+            Err(LawIdError::InvalidDate { position: 0 })
+        } else if range.end != s.len() {
+            Err(LawIdError::InvalidDate {
+                position: range.end,
+            })
+        } else {
+            Ok(date)
+        }
+    }
+}
+
+impl_str_conversions!(WarekiDate);
+
+#[test]
+fn check_wareki_date() {
+    let date = WarekiDate::new(Era::Reiwa, 5, 6, 20).unwrap();
+    assert_eq!(WarekiDate::from_text("令和五年六月二十日"), Ok(date));
+    assert_eq!(WarekiDate::from_text("令和5年6月20日"), Ok(date));
+    assert_eq!(WarekiDate::from_text("令和５年６月２０日"), Ok(date));
+    assert_eq!(
+        WarekiDate::from_text("令和五年六月二十日法律第六十号"),
+        Ok(date)
+    );
+    assert_eq!(date.to_string(), "令和5年6月20日");
+    assert_eq!(
+        date.to_text_with(Numeral::Kansuji, true),
+        "令和五年六月二十日"
+    );
+    assert_eq!(
+        date.to_text_with(Numeral::FullWidth, true),
+        "令和５年６月２０日"
+    );
+    assert_eq!("令和5年6月20日".parse(), Ok(date));
+    assert_eq!(
+        "令和5年6月20日法律".parse::<WarekiDate>(),
+        Err(LawIdError::InvalidDate { position: 19 })
+    );
+    assert_eq!(
+        WarekiDate::from_text("令和5年2月30日"),
+        Err(LawIdError::InvalidDate { position: 0 })
+    );
+    assert_eq!(
+        WarekiDate::new(Era::Reiwa, 1, 5, 1)
+            .unwrap()
+            .to_text_with(Numeral::Arabic, true),
+        "令和元年5月1日"
+    );
+}
+
+#[test]
+fn check_wareki_date_boundary() {
+    let showa_last = WarekiDate::new(Era::Showa, 64, 1, 7).unwrap();
+    let heisei_first = WarekiDate::new(Era::Heisei, 1, 1, 8).unwrap();
+    assert_eq!(showa_last.to_date(), ymd(1989, 1, 7));
+    assert_eq!(heisei_first.to_date(), ymd(1989, 1, 8));
+    assert_eq!(WarekiDate::from_date(ymd(1989, 1, 7)), Ok(showa_last));
+    assert_eq!(WarekiDate::from_date(ymd(1989, 1, 8)), Ok(heisei_first));
+    assert!(WarekiDate::new(Era::Showa, 64, 1, 8).is_err());
+    assert!(WarekiDate::new(Era::Heisei, 1, 1, 7).is_err());
+    assert_eq!(
+        WarekiDate::from_date(ymd(2019, 4, 30)),
+        WarekiDate::new(Era::Heisei, 31, 4, 30)
+    );
+    assert_eq!(
+        WarekiDate::from_date(ymd(2019, 5, 1)),
+        WarekiDate::new(Era::Reiwa, 1, 5, 1)
+    );
+    assert!(WarekiDate::from_date(ymd(1868, 1, 1)).is_err());
+}
+
+#[test]
+fn check_wareki_date_lunisolar() {
+    let date = WarekiDate::from_text("明治三年閏十月五日").unwrap();
+    assert_eq!(
+        date,
+        WarekiDate::new_leap_month(Era::Meiji, 3, 10, 5).unwrap()
+    );
+    assert_eq!(date.to_date(), ymd(1870, 11, 27));
+    assert_eq!(date.to_string(), "明治3年閏10月5日");
+    assert_eq!(WarekiDate::from_date(date.to_date()), Ok(date));
+    // 明治四年には閏十月が無い
+    assert_eq!(
+        WarekiDate::from_text("明治四年閏十月五日"),
+        Err(LawIdError::InvalidDate { position: 0 })
+    );
+    assert_eq!(
+        WarekiDate::new(Era::Meiji, 1, 9, 8).unwrap().to_date(),
+        ymd(1868, 10, 23)
+    );
+    assert_eq!(
+        WarekiDate::from_date(ymd(1872, 12, 31)),
+        WarekiDate::new(Era::Meiji, 5, 12, 2)
+    );
+    assert_eq!(
+        WarekiDate::from_date(ymd(1873, 1, 1)),
+        WarekiDate::new(Era::Meiji, 6, 1, 1)
+    );
+    assert!(WarekiDate::new(Era::Meiji, 5, 12, 3).is_err());
+    assert!(WarekiDate::new_leap_month(Era::Reiwa, 5, 6, 1).is_err());
+    assert!(WarekiDate::new(Era::Meiji, 3, 10, 5).unwrap() < date);
+    assert_eq!(
+        Date::new_wareki(Era::Meiji, 5, 12, 2),
+        Ok(ymd(1872, 12, 31))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_wareki_date_serde() {
+    let date = WarekiDate::new_leap_month(Era::Meiji, 3, 10, 5).unwrap();
+    let json = serde_json::to_string(&date).unwrap();
+    assert_eq!(
+        json,
+        r#"{"wareki":{"era":"Meiji","year":3},"month":10,"leap_month":true,"day":5}"#
+    );
+    assert_eq!(serde_json::from_str::<WarekiDate>(&json).unwrap(), date);
+    assert!(
+        serde_json::from_str::<WarekiDate>(
+            r#"{"wareki":{"era":"Meiji","year":3},"month":13,"leap_month":true,"day":40}"#
+        )
+        .is_err()
+    );
+    assert!(
+        serde_json::from_str::<WarekiDate>(
+            r#"{"wareki":{"era":"Showa","year":64},"month":1,"leap_month":false,"day":8}"#
+        )
+        .is_err()
+    );
+}
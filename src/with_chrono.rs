@@ -0,0 +1,79 @@
+//! `chrono`の日付との相互変換
+
+use crate::*;
+use chrono::{Datelike, NaiveDate};
+
+impl TryFrom<Date> for NaiveDate {
+    type Error = LawIdError;
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        i32::try_from(date.year)
+            .ok()
+            .and_then(|year| NaiveDate::from_ymd_opt(year, date.month as u32, date.day as u32))
+            .ok_or(LawIdError::InvalidDate { position: 0 })
+    }
+}
+
+/// 紀元前の日付は`LawIdError::InvalidDate`になる
+impl TryFrom<NaiveDate> for Date {
+    type Error = LawIdError;
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        let year =
+            usize::try_from(date.year()).map_err(|_| LawIdError::InvalidDate { position: 0 })?;
+        Date::new_ad(year, date.month() as usize, date.day() as usize)
+    }
+}
+
+impl TryFrom<WarekiDate> for NaiveDate {
+    type Error = LawIdError;
+    fn try_from(date: WarekiDate) -> Result<Self, Self::Error> {
+        date.to_date().try_into()
+    }
+}
+
+impl TryFrom<NaiveDate> for WarekiDate {
+    type Error = LawIdError;
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        WarekiDate::from_date(date.try_into()?)
+    }
+}
+
+impl Wareki {
+    /// `chrono::NaiveDate`から和暦の年を求める
+    pub fn from_naive_date(date: NaiveDate) -> Result<Self, LawIdError> {
+        Date::try_from(date)?.gen_wareki_year()
+    }
+}
+
+impl Era {
+    /// `chrono::NaiveDate`の日付の元号を求める
+    pub fn from_naive_date(date: NaiveDate) -> Result<Self, LawIdError> {
+        Self::from_date(date.try_into()?)
+    }
+}
+
+#[test]
+fn check_chrono() {
+    let naive = NaiveDate::from_ymd_opt(1989, 1, 8).unwrap();
+    assert_eq!(Date::try_from(naive), Date::new_ad(1989, 1, 8));
+    assert_eq!(
+        NaiveDate::try_from(Date::new_ad(1989, 1, 8).unwrap()),
+        Ok(naive)
+    );
+    assert_eq!(
+        WarekiDate::try_from(naive),
+        WarekiDate::new(Era::Heisei, 1, 1, 8)
+    );
+    assert_eq!(
+        NaiveDate::try_from(WarekiDate::new(Era::Heisei, 1, 1, 8).unwrap()),
+        Ok(naive)
+    );
+    assert_eq!(
+        Wareki::from_naive_date(naive),
+        Ok(Wareki::new(Era::Heisei, 1))
+    );
+    assert_eq!(
+        Era::from_naive_date(naive.pred_opt().unwrap()),
+        Ok(Era::Showa)
+    );
+    assert!(Date::try_from(NaiveDate::from_ymd_opt(10000, 1, 1).unwrap()).is_err());
+}
@@ -0,0 +1,74 @@
+//! `jiff`の日付との相互変換
+
+use crate::*;
+use jiff::civil;
+
+impl TryFrom<Date> for civil::Date {
+    type Error = LawIdError;
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        let err = LawIdError::InvalidDate { position: 0 };
+        let year = i16::try_from(date.year).map_err(|_| err.clone())?;
+        let month = i8::try_from(date.month).map_err(|_| err.clone())?;
+        let day = i8::try_from(date.day).map_err(|_| err.clone())?;
+        civil::Date::new(year, month, day).map_err(|_| err)
+    }
+}
+
+/// 紀元前の日付は`LawIdError::InvalidDate`になる
+impl TryFrom<civil::Date> for Date {
+    type Error = LawIdError;
+    fn try_from(date: civil::Date) -> Result<Self, Self::Error> {
+        let year =
+            usize::try_from(date.year()).map_err(|_| LawIdError::InvalidDate { position: 0 })?;
+        Date::new_ad(year, date.month() as usize, date.day() as usize)
+    }
+}
+
+impl TryFrom<WarekiDate> for civil::Date {
+    type Error = LawIdError;
+    fn try_from(date: WarekiDate) -> Result<Self, Self::Error> {
+        date.to_date().try_into()
+    }
+}
+
+impl TryFrom<civil::Date> for WarekiDate {
+    type Error = LawIdError;
+    fn try_from(date: civil::Date) -> Result<Self, Self::Error> {
+        WarekiDate::from_date(date.try_into()?)
+    }
+}
+
+impl Wareki {
+    /// `jiff::civil::Date`から和暦の年を求める
+    pub fn from_jiff_date(date: civil::Date) -> Result<Self, LawIdError> {
+        Date::try_from(date)?.gen_wareki_year()
+    }
+}
+
+impl Era {
+    /// `jiff::civil::Date`の日付の元号を求める
+    pub fn from_jiff_date(date: civil::Date) -> Result<Self, LawIdError> {
+        Self::from_date(date.try_into()?)
+    }
+}
+
+#[test]
+fn check_jiff() {
+    let d = civil::date(1926, 12, 25);
+    assert_eq!(Date::try_from(d), Date::new_ad(1926, 12, 25));
+    assert_eq!(
+        civil::Date::try_from(Date::new_ad(1926, 12, 25).unwrap()),
+        Ok(d)
+    );
+    assert_eq!(
+        WarekiDate::try_from(d),
+        WarekiDate::new(Era::Showa, 1, 12, 25)
+    );
+    assert_eq!(
+        civil::Date::try_from(WarekiDate::new(Era::Showa, 1, 12, 25).unwrap()),
+        Ok(d)
+    );
+    assert_eq!(Wareki::from_jiff_date(d), Ok(Wareki::new(Era::Showa, 1)));
+    assert_eq!(Era::from_jiff_date(d.yesterday().unwrap()), Ok(Era::Taisho));
+    assert!(Date::try_from(civil::Date::MIN).is_err());
+}
@@ -0,0 +1,79 @@
+//! `time`の日付との相互変換
+
+use crate::*;
+
+impl TryFrom<Date> for time::Date {
+    type Error = LawIdError;
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        let err = LawIdError::InvalidDate { position: 0 };
+        let year = i32::try_from(date.year).map_err(|_| err.clone())?;
+        let month = u8::try_from(date.month)
+            .ok()
+            .and_then(|m| time::Month::try_from(m).ok())
+            .ok_or(err.clone())?;
+        let day = u8::try_from(date.day).map_err(|_| err.clone())?;
+        time::Date::from_calendar_date(year, month, day).map_err(|_| err)
+    }
+}
+
+/// 紀元前の日付は`LawIdError::InvalidDate`になる
+impl TryFrom<time::Date> for Date {
+    type Error = LawIdError;
+    fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+        let year =
+            usize::try_from(date.year()).map_err(|_| LawIdError::InvalidDate { position: 0 })?;
+        Date::new_ad(year, u8::from(date.month()) as usize, date.day() as usize)
+    }
+}
+
+impl TryFrom<WarekiDate> for time::Date {
+    type Error = LawIdError;
+    fn try_from(date: WarekiDate) -> Result<Self, Self::Error> {
+        date.to_date().try_into()
+    }
+}
+
+impl TryFrom<time::Date> for WarekiDate {
+    type Error = LawIdError;
+    fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+        WarekiDate::from_date(date.try_into()?)
+    }
+}
+
+impl Wareki {
+    /// `time::Date`から和暦の年を求める
+    pub fn from_time_date(date: time::Date) -> Result<Self, LawIdError> {
+        Date::try_from(date)?.gen_wareki_year()
+    }
+}
+
+impl Era {
+    /// `time::Date`の日付の元号を求める
+    pub fn from_time_date(date: time::Date) -> Result<Self, LawIdError> {
+        Self::from_date(date.try_into()?)
+    }
+}
+
+#[test]
+fn check_time() {
+    let t = time::Date::from_calendar_date(2019, time::Month::May, 1).unwrap();
+    assert_eq!(Date::try_from(t), Date::new_ad(2019, 5, 1));
+    assert_eq!(
+        time::Date::try_from(Date::new_ad(2019, 5, 1).unwrap()),
+        Ok(t)
+    );
+    assert_eq!(
+        WarekiDate::try_from(t),
+        WarekiDate::new(Era::Reiwa, 1, 5, 1)
+    );
+    assert_eq!(
+        time::Date::try_from(WarekiDate::new(Era::Reiwa, 1, 5, 1).unwrap()),
+        Ok(t)
+    );
+    assert_eq!(Wareki::from_time_date(t), Ok(Wareki::new(Era::Reiwa, 1)));
+    assert_eq!(
+        Era::from_time_date(t.previous_day().unwrap()),
+        Ok(Era::Heisei)
+    );
+    assert!(Date::try_from(time::Date::MIN).is_err());
+}
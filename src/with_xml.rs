@@ -0,0 +1,110 @@
+//! `quick-xml`による法令標準XMLの読み込み
+
+use crate::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, XmlVersion};
+
+impl LawId {
+    /// 法令標準XMLの文書のルートの`<Law>`要素の属性と`<LawNum>`要素から生成する
+    ///
+    /// XMLとして読めない場合や，必要な属性や要素が無い場合は`LawIdError::InvalidXml`になる．
+    /// 属性や法令番号の値の問題は`LawId::from_xml_attributes`と同じエラーになり，
+    /// `position`はそれぞれの値の中での位置を表す
+    pub fn from_xml(xml: &str) -> Result<Self, LawIdError> {
+        let mut reader = Reader::from_str(xml);
+        let invalid = |reader: &Reader<&[u8]>| LawIdError::InvalidXml {
+            position: reader.buffer_position() as usize,
+        };
+        let mut law = None;
+        let mut law_num: Option<String> = None;
+        let mut in_law_num = false;
+        loop {
+            match reader.read_event().map_err(|_| invalid(&reader))? {
+                Event::Start(e) if law.is_none() => {
+                    if e.name().as_ref() != "Law" {
+                        return Err(invalid(&reader));
+                    }
+                    law = Some(read_law_attributes(&e).ok_or(invalid(&reader))?);
+                }
+                Event::Start(e) if e.name().as_ref() == "LawNum" => in_law_num = true,
+                Event::Empty(e) if law.is_some() && e.name().as_ref() == "LawNum" => {
+                    law_num = Some(String::new());
+                    break;
+                }
+                Event::Text(t) if in_law_num => {
+                    law_num
+                        .get_or_insert_with(String::new)
+                        .push_str(t.xml10_content().trim());
+                }
+                Event::End(e) if in_law_num && e.name().as_ref() == "LawNum" => {
+                    law_num.get_or_insert_with(String::new);
+                    break;
+                }
+                Event::Eof => break,
+                _ => (),
+            }
+        }
+        let [era, year, law_type, num] = law.ok_or(invalid(&reader))?;
+        let law_num = law_num.ok_or(invalid(&reader))?;
+        Self::from_xml_attributes(&LawXmlAttributes {
+            era: &era,
+            year: &year,
+            law_type: &law_type,
+            num: &num,
+            law_num: &law_num,
+        })
+    }
+}
+
+/// `<Law>`要素の`Era`，`Year`，`LawType`，`Num`属性を読む
+///
+/// `Num`属性が無い（憲法など）場合は空文字列とする
+fn read_law_attributes(e: &BytesStart<'_>) -> Option<[String; 4]> {
+    let mut values: [Option<String>; 4] = Default::default();
+    for attribute in e.attributes() {
+        let attribute = attribute.ok()?;
+        let i = match attribute.key.as_ref() {
+            "Era" => 0,
+            "Year" => 1,
+            "LawType" => 2,
+            "Num" => 3,
+            _ => continue,
+        };
+        let value = attribute.normalized_value(XmlVersion::Implicit1_0).ok()?;
+        values[i] = Some(value.into_owned());
+    }
+    let [era, year, law_type, num] = values;
+    Some([era?, year?, law_type?, num.unwrap_or_default()])
+}
+
+#[test]
+fn check_from_xml() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Law Era="Showa" Lang="ja" LawType="MinisterialOrdinance" Num="4" Year="25">
+  <LawNum>昭和二十五年郵政省令第四号</LawNum>
+  <LawBody>
+    <LawTitle>電波法施行規則</LawTitle>
+  </LawBody>
+</Law>"#;
+    assert_eq!(LawId::from_xml(xml), LawId::from_id_str("325M50001000004"));
+    let xml = r#"<Law Era="Showa" Lang="ja" LawType="MinisterialOrdinance" Num="5" Year="25">
+  <LawNum>昭和二十五年郵政省令第四号</LawNum>
+</Law>"#;
+    assert_eq!(
+        LawId::from_xml(xml),
+        Err(LawIdError::XmlAttributeMismatch {
+            position: 0,
+            attribute: "Num"
+        })
+    );
+    assert!(matches!(
+        LawId::from_xml(
+            r#"<Law Era="Showa" Year="25"><LawNum>昭和二十五年郵政省令第四号</LawNum></Law>"#
+        ),
+        Err(LawIdError::InvalidXml { .. })
+    ));
+    assert!(matches!(
+        LawId::from_xml("<Law"),
+        Err(LawIdError::InvalidXml { .. })
+    ));
+}
@@ -0,0 +1,352 @@
+//! 法令標準XMLの`<Law>`要素の属性と`<LawNum>`からの法令IDの生成
+//!
+//! 法令標準XMLスキーマの詳細は <https://laws.e-gov.go.jp/docs/law-data-basic/607318a-lawtypes-and-xml-schema/> を参照
+
+use crate::*;
+
+/// 法令標準XMLの`LawType`属性の値
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum XmlLawType {
+    /// 憲法
+    Constitution,
+    /// 法律
+    Act,
+    /// 政令
+    CabinetOrder,
+    /// 勅令
+    ImperialOrder,
+    /// 府省令
+    MinisterialOrdinance,
+    /// 規則
+    Rule,
+    /// その他（太政官布告など）
+    Misc,
+}
+
+impl XmlLawType {
+    const ALL: [Self; 7] = [
+        Self::Constitution,
+        Self::Act,
+        Self::CabinetOrder,
+        Self::ImperialOrder,
+        Self::MinisterialOrdinance,
+        Self::Rule,
+        Self::Misc,
+    ];
+
+    /// 「MinisterialOrdinance」のような属性値
+    pub fn as_xml_str(self) -> &'static str {
+        match self {
+            Self::Constitution => "Constitution",
+            Self::Act => "Act",
+            Self::CabinetOrder => "CabinetOrder",
+            Self::ImperialOrder => "ImperialOrder",
+            Self::MinisterialOrdinance => "MinisterialOrdinance",
+            Self::Rule => "Rule",
+            Self::Misc => "Misc",
+        }
+    }
+
+    /// 属性値から生成
+    pub fn from_xml_str(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.as_xml_str() == s)
+    }
+}
+
+impl Era {
+    /// 法令標準XMLの`Era`属性の値（「Showa」など）
+    pub fn as_xml_str(self) -> &'static str {
+        match self {
+            Self::Meiji => "Meiji",
+            Self::Taisho => "Taisho",
+            Self::Showa => "Showa",
+            Self::Heisei => "Heisei",
+            Self::Reiwa => "Reiwa",
+        }
+    }
+
+    /// 法令標準XMLの`Era`属性の値から生成
+    pub fn from_xml_str(s: &str) -> Option<Self> {
+        ERA_BOUNDARIES
+            .iter()
+            .map(|b| b.era)
+            .find(|era| era.as_xml_str() == s)
+    }
+}
+
+impl LawType {
+    /// 法令標準XMLの`LawType`属性の値
+    pub fn xml_law_type(&self) -> XmlLawType {
+        use LawType::*;
+        match self {
+            Constitution => XmlLawType::Constitution,
+            Act { .. } => XmlLawType::Act,
+            CabinetOrder { .. } => XmlLawType::CabinetOrder,
+            ImperialOrder { .. } => XmlLawType::ImperialOrder,
+            MinistryOrder { .. } => XmlLawType::MinisterialOrdinance,
+            Jinjin { .. } | Regulation { .. } | PrimeMinisterDecision { .. } => XmlLawType::Rule,
+            DajokanFukoku { .. } | DajokanTasshi { .. } | DajokanHutatsu { .. } => XmlLawType::Misc,
+        }
+    }
+
+    /// 法令標準XMLの`Num`属性に現れる番号
+    pub(crate) fn xml_nums(&self) -> Vec<usize> {
+        use LawType::*;
+        match self {
+            Constitution => vec![],
+            Act { num, .. }
+            | CabinetOrder { num, .. }
+            | ImperialOrder { num, .. }
+            | DajokanFukoku { num, .. }
+            | DajokanTasshi { num, .. }
+            | DajokanHutatsu { num, .. }
+            | MinistryOrder { num, .. }
+            | Regulation { num, .. }
+            | PrimeMinisterDecision { num, .. } => vec![*num],
+            Jinjin {
+                kind,
+                kind_serial_number,
+                amendment_serial_number,
+            } => {
+                let mut nums = vec![*kind, *kind_serial_number];
+                if *amendment_serial_number != 0 {
+                    nums.push(*amendment_serial_number);
+                }
+                nums
+            }
+        }
+    }
+}
+
+/// 法令標準XMLの`<Law>`要素の属性と`<LawNum>`要素の内容
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct LawXmlAttributes<'a> {
+    /// `Era`属性（「Showa」など）
+    pub era: &'a str,
+    /// `Year`属性（「25」など）
+    pub year: &'a str,
+    /// `LawType`属性（「MinisterialOrdinance」など）
+    pub law_type: &'a str,
+    /// `Num`属性（「4」など）
+    pub num: &'a str,
+    /// `<LawNum>`要素の内容（「昭和二十五年郵政省令第四号」など）
+    pub law_num: &'a str,
+}
+
+impl LawId {
+    /// 法令標準XMLの属性と法令番号から生成する
+    ///
+    /// 属性の値が不正な場合は`LawIdError::InvalidXmlAttribute`に，
+    /// 属性と法令番号が食い違う場合は`LawIdError::XmlAttributeMismatch`になる．
+    /// `position`は属性の値や法令番号の中での位置を表す．
+    /// 属性と法令番号を合わせても一意に定まらない場合は`LawIdError::AmbiguousLawNum`になる
+    pub fn from_xml_attributes(attributes: &LawXmlAttributes<'_>) -> Result<Self, LawIdError> {
+        let invalid = |attribute| LawIdError::InvalidXmlAttribute {
+            position: 0,
+            attribute,
+        };
+        let era = Era::from_xml_str(attributes.era).ok_or(invalid("Era"))?;
+        let year = attributes
+            .year
+            .parse::<usize>()
+            .map_err(|_| invalid("Year"))?;
+        let wareki = Wareki::new(era, year);
+        let law_type = XmlLawType::from_xml_str(attributes.law_type).ok_or(invalid("LawType"))?;
+        if law_type == XmlLawType::Constitution {
+            return Ok(Self {
+                wareki,
+                law_type: LawType::Constitution,
+            });
+        }
+        let nums = parse_xml_num(attributes.num)?;
+
+        let mismatch = |attribute| LawIdError::XmlAttributeMismatch {
+            position: 0,
+            attribute,
+        };
+        let mut candidates = Self::candidates_from_law_num(attributes.law_num)?;
+        candidates.retain(|id| id.wareki.get_era() == era);
+        if candidates.is_empty() {
+            return Err(mismatch("Era"));
+        }
+        candidates.retain(|id| id.wareki.get_year() == year);
+        if candidates.is_empty() {
+            return Err(mismatch("Year"));
+        }
+        candidates.retain(|id| id.law_type.xml_law_type() == law_type);
+        if candidates.is_empty() {
+            return Err(mismatch("LawType"));
+        }
+        candidates.retain(|id| id.law_type.xml_nums() == nums);
+        if candidates.is_empty() {
+            return Err(mismatch("Num"));
+        }
+        if candidates.len() == 1 {
+            Ok(candidates.remove(0))
+        } else {
+            Err(LawIdError::AmbiguousLawNum {
+                position: 0,
+                candidates,
+            })
+        }
+    }
+}
+
+/// 「4」や「14_7」のような`Num`属性の値を番号の列として解析する
+///
+/// 法令IDの3桁の欄に収まらない番号は`LawIdError::InvalidXmlAttribute`になる
+fn parse_xml_num(s: &str) -> Result<Vec<usize>, LawIdError> {
+    let mut nums = Vec::new();
+    let mut start = 0;
+    for (i, c) in s.char_indices().chain([(s.len(), '_')]) {
+        if c.is_ascii_digit() {
+            continue;
+        }
+        if start == i {
+            return Err(LawIdError::InvalidXmlAttribute {
+                position: i,
+                attribute: "Num",
+            });
+        }
+        let num = parse_digits(s, start..i)
+            .ok()
+            .filter(|num| *num <= MAX_3_DIGITS)
+            .ok_or(LawIdError::InvalidXmlAttribute {
+                position: start,
+                attribute: "Num",
+            })?;
+        nums.push(num);
+        start = i + c.len_utf8();
+    }
+    Ok(nums)
+}
+
+#[test]
+fn check_from_xml_attributes() {
+    let attributes = LawXmlAttributes {
+        era: "Showa",
+        year: "25",
+        law_type: "MinisterialOrdinance",
+        num: "4",
+        law_num: "昭和二十五年郵政省令第四号",
+    };
+    assert_eq!(
+        LawId::from_xml_attributes(&attributes),
+        LawId::from_id_str("325M50001000004")
+    );
+    assert_eq!(
+        LawId::from_xml_attributes(&LawXmlAttributes {
+            era: "Reiwa",
+            year: "5",
+            law_type: "Act",
+            num: "60",
+            law_num: "令和五年法律第六十号",
+        }),
+        Err(LawIdError::AmbiguousLawNum {
+            position: 0,
+            candidates: LawId::candidates_from_law_num("令和五年法律第六十号").unwrap()
+        })
+    );
+    assert_eq!(
+        LawId::from_xml_attributes(&LawXmlAttributes {
+            era: "Heisei",
+            year: "10",
+            law_type: "Rule",
+            num: "14_7",
+            law_num: "平成十年人事院規則一四―七",
+        }),
+        LawId::from_id_str("410RJNJ14007000")
+    );
+    assert_eq!(
+        LawId::from_xml_attributes(&LawXmlAttributes {
+            era: "Showa",
+            year: "21",
+            law_type: "Constitution",
+            num: "",
+            law_num: "",
+        }),
+        LawId::from_id_str("321CONSTITUTION")
+    );
+}
+
+#[test]
+fn check_from_xml_attributes_errors() {
+    let attributes = LawXmlAttributes {
+        era: "Showa",
+        year: "25",
+        law_type: "MinisterialOrdinance",
+        num: "4",
+        law_num: "昭和二十五年郵政省令第四号",
+    };
+    assert_eq!(
+        LawId::from_xml_attributes(&LawXmlAttributes {
+            era: "Syowa",
+            ..attributes
+        }),
+        Err(LawIdError::InvalidXmlAttribute {
+            position: 0,
+            attribute: "Era"
+        })
+    );
+    assert_eq!(
+        LawId::from_xml_attributes(&LawXmlAttributes {
+            year: "26",
+            ..attributes
+        }),
+        Err(LawIdError::XmlAttributeMismatch {
+            position: 0,
+            attribute: "Year"
+        })
+    );
+    assert_eq!(
+        LawId::from_xml_attributes(&LawXmlAttributes {
+            law_type: "CabinetOrder",
+            ..attributes
+        }),
+        Err(LawIdError::XmlAttributeMismatch {
+            position: 0,
+            attribute: "LawType"
+        })
+    );
+    assert_eq!(
+        LawId::from_xml_attributes(&LawXmlAttributes {
+            num: "5",
+            ..attributes
+        }),
+        Err(LawIdError::XmlAttributeMismatch {
+            position: 0,
+            attribute: "Num"
+        })
+    );
+    assert_eq!(
+        LawId::from_xml_attributes(&LawXmlAttributes {
+            num: "4_",
+            ..attributes
+        }),
+        Err(LawIdError::InvalidXmlAttribute {
+            position: 2,
+            attribute: "Num"
+        })
+    );
+    assert_eq!(
+        LawId::from_xml_attributes(&LawXmlAttributes {
+            num: "99999999999999999999999",
+            ..attributes
+        }),
+        Err(LawIdError::InvalidXmlAttribute {
+            position: 0,
+            attribute: "Num"
+        })
+    );
+    assert_eq!(
+        LawId::from_xml_attributes(&LawXmlAttributes {
+            num: "14_1000",
+            ..attributes
+        }),
+        Err(LawIdError::InvalidXmlAttribute {
+            position: 3,
+            attribute: "Num"
+        })
+    );
+}